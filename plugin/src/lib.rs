@@ -1,8 +1,12 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
 use swc_core::{
+    common::sync::Lrc,
     ecma::{ast::Program, visit::visit_mut_pass},
-    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+    plugin::{
+        metadata::TransformPluginMetadataContextKind, plugin_transform,
+        proxies::TransformPluginProgramMetadata,
+    },
 };
 use swc_vue_jsx_visitor::VueJsxTransformVisitor;
 
@@ -14,9 +18,14 @@ pub fn vue_jsx(program: Program, metadata: TransformPluginProgramMetadata) -> Pr
             serde_json::from_str(&json).expect("failed to parse config of plugin 'vue-jsx'")
         })
         .unwrap_or_default();
-    program.apply(visit_mut_pass(&mut VueJsxTransformVisitor::new(
-        options,
-        metadata.unresolved_mark,
-        metadata.comments,
-    )))
+    let mut visitor =
+        VueJsxTransformVisitor::new(options, metadata.unresolved_mark, metadata.comments)
+            .with_source_map(Lrc::new(metadata.source_map.clone()));
+    if let Some(file_name) = metadata.get_context(&TransformPluginMetadataContextKind::Filename) {
+        visitor = visitor.with_file_name(file_name);
+    }
+    if let Some(cwd) = metadata.get_context(&TransformPluginMetadataContextKind::Cwd) {
+        visitor = visitor.with_cwd(cwd);
+    }
+    program.apply(visit_mut_pass(&mut visitor))
 }
@@ -0,0 +1,295 @@
+use swc_core::{
+    common::{Span, DUMMY_SP},
+    ecma::{
+        ast::*,
+        utils::{quote_ident, quote_str},
+    },
+};
+
+/// Fold static `class` value like `['a', { b: true }]` to `"a b"` at compile time,
+/// otherwise wrap it with `normalizeClass`, where static elements of array are still folded.
+/// The `helper` callback returns the helper identifier and span of the call.
+pub(crate) fn normalize_class(
+    expr: Box<Expr>,
+    helper: impl FnOnce() -> (Ident, Span),
+) -> Box<Expr> {
+    if let Expr::Lit(Lit::Str(..)) = &*expr {
+        return expr;
+    }
+    if let Some(class) = static_class(&expr) {
+        return Box::new(Expr::Lit(Lit::Str(quote_str!(class))));
+    }
+
+    let expr = if let Expr::Array(ArrayLit { elems, .. }) = *expr {
+        let mut folded: Vec<Option<ExprOrSpread>> = Vec::with_capacity(elems.len());
+        for elem in elems.into_iter().flatten() {
+            let class = if elem.spread.is_none() {
+                static_class(&elem.expr)
+            } else {
+                None
+            };
+            match (class, folded.last_mut()) {
+                (Some(class), _) if class.is_empty() => {}
+                (
+                    Some(class),
+                    Some(Some(ExprOrSpread {
+                        spread: None,
+                        expr: last,
+                    })),
+                ) if last.is_lit() => {
+                    if let Expr::Lit(Lit::Str(str)) = &mut **last {
+                        *str = quote_str!(format!("{} {class}", str.value));
+                    }
+                }
+                (Some(class), _) => folded.push(Some(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(class)))),
+                })),
+                (None, _) => folded.push(Some(elem)),
+            }
+        }
+        Box::new(Expr::Array(ArrayLit {
+            elems: folded,
+            span: DUMMY_SP,
+        }))
+    } else {
+        expr
+    };
+    Box::new(build_helper_call(helper(), expr))
+}
+
+/// Fold static `style` value like `"color: red; margin: 0"` to object form at compile time,
+/// otherwise wrap it with `normalizeStyle`, where static elements of array are still folded.
+/// Like Vue compiler, dynamic `style` of component is only normalized if it's an array literal,
+/// so the value is passed to component as-is.
+/// The `helper` callback returns the helper identifier and span of the call.
+pub(crate) fn normalize_style(
+    expr: Box<Expr>,
+    is_component: bool,
+    helper: impl FnOnce() -> (Ident, Span),
+) -> Box<Expr> {
+    if let Some(style) = static_style(&expr) {
+        return Box::new(build_style_object(style));
+    }
+    if is_component && !expr.is_array() {
+        return expr;
+    }
+
+    let expr = if let Expr::Array(ArrayLit { elems, .. }) = *expr {
+        let mut folded = Vec::with_capacity(elems.len());
+        let mut pending: Option<Vec<(String, Lit)>> = None;
+        for elem in elems.into_iter().flatten() {
+            let style = if elem.spread.is_none() {
+                static_style(&elem.expr)
+            } else {
+                None
+            };
+            if let Some(style) = style {
+                merge_style(pending.get_or_insert_with(Vec::new), style);
+            } else {
+                if let Some(style) = pending.take() {
+                    folded.push(Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(build_style_object(style)),
+                    }));
+                }
+                folded.push(Some(elem));
+            }
+        }
+        if let Some(style) = pending {
+            folded.push(Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(build_style_object(style)),
+            }));
+        }
+        Box::new(Expr::Array(ArrayLit {
+            elems: folded,
+            span: DUMMY_SP,
+        }))
+    } else {
+        expr
+    };
+    Box::new(build_helper_call(helper(), expr))
+}
+
+/// Same as `normalizeClass` of Vue, but returns `None` if there're dynamic parts.
+fn static_class(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(str.value.to_string()),
+        Expr::Array(ArrayLit { elems, .. }) => {
+            let mut class = String::new();
+            for elem in elems.iter().flatten() {
+                if elem.spread.is_some() {
+                    return None;
+                }
+                let normalized = static_class(&elem.expr)?;
+                if !normalized.is_empty() {
+                    class.push_str(&normalized);
+                    class.push(' ');
+                }
+            }
+            Some(class.trim().to_string())
+        }
+        Expr::Object(ObjectLit { props, .. }) => {
+            let mut classes = Vec::with_capacity(props.len());
+            for prop in props {
+                match prop.as_prop().map(|prop| &**prop)? {
+                    Prop::KeyValue(KeyValueProp { key, value }) => {
+                        let name = match key {
+                            PropName::Ident(ident) => ident.sym.to_string(),
+                            PropName::Str(str) => str.value.to_string(),
+                            PropName::Num(num) => num.value.to_string(),
+                            _ => return None,
+                        };
+                        if is_static_truthy(value)? {
+                            classes.push(name);
+                        }
+                    }
+                    Prop::Shorthand(ident) if ident.sym == "undefined" => {}
+                    _ => return None,
+                }
+            }
+            Some(classes.join(" "))
+        }
+        Expr::Lit(..) => Some(String::new()),
+        Expr::Ident(ident) if ident.sym == "undefined" => Some(String::new()),
+        Expr::Paren(ParenExpr { expr, .. }) => static_class(expr),
+        _ => None,
+    }
+}
+
+fn is_static_truthy(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(!str.value.is_empty()),
+        Expr::Lit(Lit::Num(num)) => Some(num.value != 0.0 && !num.value.is_nan()),
+        Expr::Lit(Lit::Bool(bool)) => Some(bool.value),
+        Expr::Lit(Lit::Null(..)) => Some(false),
+        Expr::Ident(ident) if ident.sym == "undefined" => Some(false),
+        Expr::Paren(ParenExpr { expr, .. }) => is_static_truthy(expr),
+        _ => None,
+    }
+}
+
+/// Same as `normalizeStyle` of Vue, but returns `None` if there're dynamic parts.
+fn static_style(expr: &Expr) -> Option<Vec<(String, Lit)>> {
+    match expr {
+        Expr::Lit(Lit::Str(str)) => Some(parse_string_style(&str.value)),
+        Expr::Object(ObjectLit { props, .. }) => {
+            let mut style = Vec::with_capacity(props.len());
+            for prop in props {
+                let Prop::KeyValue(KeyValueProp { key, value }) =
+                    prop.as_prop().map(|prop| &**prop)?
+                else {
+                    return None;
+                };
+                let name = match key {
+                    PropName::Ident(ident) => ident.sym.to_string(),
+                    PropName::Str(str) => str.value.to_string(),
+                    _ => return None,
+                };
+                match &**value {
+                    Expr::Lit(lit @ (Lit::Str(..) | Lit::Num(..))) => {
+                        merge_style(&mut style, vec![(name, lit.clone())]);
+                    }
+                    _ => return None,
+                }
+            }
+            Some(style)
+        }
+        Expr::Array(ArrayLit { elems, .. }) => {
+            let mut style = vec![];
+            for elem in elems.iter().flatten() {
+                if elem.spread.is_some() {
+                    return None;
+                }
+                merge_style(&mut style, static_style(&elem.expr)?);
+            }
+            Some(style)
+        }
+        Expr::Lit(Lit::Null(..) | Lit::Bool(..)) => Some(vec![]),
+        Expr::Ident(ident) if ident.sym == "undefined" => Some(vec![]),
+        Expr::Paren(ParenExpr { expr, .. }) => static_style(expr),
+        _ => None,
+    }
+}
+
+/// Later declarations override previous ones, while keeping the original order of keys.
+fn merge_style(style: &mut Vec<(String, Lit)>, other: Vec<(String, Lit)>) {
+    for (name, value) in other {
+        if let Some((_, existing)) = style.iter_mut().find(|(key, _)| *key == name) {
+            *existing = value;
+        } else {
+            style.push((name, value));
+        }
+    }
+}
+
+/// Same as `parseStringStyle` of Vue: `"color: red; background: url(a;b)"`
+/// becomes `[("color", "red"), ("background", "url(a;b)")]`.
+fn parse_string_style(css_text: &str) -> Vec<(String, Lit)> {
+    let mut css = String::with_capacity(css_text.len());
+    let mut rest = css_text;
+    while let Some(start) = rest.find("/*") {
+        css.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map(|end| &rest[start + 2 + end + 2..])
+            .unwrap_or_default();
+    }
+    css.push_str(rest);
+
+    let mut declarations = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in css.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => {
+                declarations.push(&css[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&css[start..]);
+
+    let mut style = vec![];
+    for declaration in declarations {
+        if let Some((name, value)) = declaration.split_once(':') {
+            merge_style(
+                &mut style,
+                vec![(name.trim().to_string(), Lit::Str(quote_str!(value.trim())))],
+            );
+        }
+    }
+    style
+}
+
+fn build_style_object(style: Vec<(String, Lit)>) -> Expr {
+    Expr::Object(ObjectLit {
+        props: style
+            .into_iter()
+            .map(|(name, value)| {
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: if Ident::verify_symbol(&name).is_ok() {
+                        PropName::Ident(quote_ident!(name))
+                    } else {
+                        PropName::Str(quote_str!(name))
+                    },
+                    value: Box::new(Expr::Lit(value)),
+                })))
+            })
+            .collect(),
+        span: DUMMY_SP,
+    })
+}
+
+fn build_helper_call((helper, span): (Ident, Span), expr: Box<Expr>) -> Expr {
+    Expr::Call(CallExpr {
+        span,
+        callee: Callee::Expr(Box::new(Expr::Ident(helper))),
+        args: vec![ExprOrSpread { spread: None, expr }],
+        ..Default::default()
+    })
+}
@@ -1,12 +1,16 @@
 use directive::{is_directive, parse_directive, Directive, NormalDirective};
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use indexmap::IndexSet;
-pub use options::{Options, Regex};
+pub use metadata::{ComponentMetadata, PropMetadata};
+pub use options::{DevSourceLocations, Options, Regex, ScopeId, TypeResolverOptions, Whitespace};
 use patch_flags::PatchFlags;
 use slot_flag::SlotFlag;
-use std::{borrow::Cow, collections::BTreeMap, mem};
+use std::{borrow::Cow, collections::BTreeMap, mem, path::Path};
 use swc_core::{
-    common::{comments::Comments, Mark, Span, Spanned, SyntaxContext, DUMMY_SP},
+    common::{
+        comments::Comments, errors::SourceMapper, sync::Lrc, util::take::Take, Mark, Span, Spanned,
+        SyntaxContext, DUMMY_SP,
+    },
     ecma::{
         ast::*,
         atoms::Atom,
@@ -15,17 +19,28 @@ use swc_core::{
     },
     plugin::errors::HANDLER,
 };
+use type_resolver::{TypeDecls, TypeResolver};
 
+mod class_style;
 mod directive;
+mod metadata;
 mod options;
 mod patch_flags;
+mod props_destructure;
 mod resolve_type;
 mod slot_flag;
+mod type_resolver;
 mod util;
 
 const FRAGMENT: &str = "Fragment";
 const KEEP_ALIVE: &str = "KeepAlive";
 
+#[derive(Clone)]
+enum TextPart<'a> {
+    Static(String),
+    Dynamic(&'a Expr),
+}
+
 struct AttrsTransformationResult<'a> {
     attrs: Expr,
     patch_flags: PatchFlags,
@@ -41,18 +56,40 @@ where
     vue_imports: BTreeMap<&'static str, Ident>,
     transform_on_helper: Option<Ident>,
 
-    define_component: Option<SyntaxContext>,
+    define_component: FnvHashSet<(Atom, SyntaxContext)>,
+    vue_namespaces: FnvHashSet<(Atom, SyntaxContext)>,
     interfaces: FnvHashMap<(Atom, SyntaxContext), TsInterfaceDecl>,
     type_aliases: FnvHashMap<(Atom, SyntaxContext), TsType>,
+    type_alias_params: FnvHashMap<(Atom, SyntaxContext), TsTypeParamDecl>,
+    /// Type aliases with type arguments that are being resolved, used to stop recursive types.
+    resolving_type_aliases: resolve_type::ResolvingTypeAliases,
+    enums: FnvHashMap<(Atom, SyntaxContext), TsEnumDecl>,
+    /// Inferred types of local bindings, which are used to resolve `typeof` type queries.
+    value_types: FnvHashMap<(Atom, SyntaxContext), TsType>,
+    /// Local bindings which always hold primitive values, so they can be compiled as texts.
+    text_bindings: FnvHashSet<(Atom, SyntaxContext)>,
+    type_resolver: Option<TypeResolver>,
+    imported_types: Vec<((Atom, SyntaxContext), Atom, Atom)>,
+    /// Identifiers referenced in types of current file.
+    type_references: FnvHashSet<(Atom, SyntaxContext)>,
+    type_params: FnvHashMap<(Atom, SyntaxContext), Option<Box<TsType>>>,
+    component_metadata: Vec<ComponentMetadata>,
 
     unresolved_mark: Mark,
     comments: Option<C>,
+    file_name: Option<String>,
+    cwd: Option<String>,
+    source_map: Option<Lrc<dyn SourceMapper>>,
+    /// Resolved scope ID like `data-v-7ba5bd90`.
+    scope_id: Option<String>,
 
     pragma: Option<String>,
     slot_helper_ident: Option<Ident>,
     injecting_vars: Vec<VarDeclarator>,
     slot_counter: usize,
     slot_flag_stack: Vec<SlotFlag>,
+    /// Depth of `<pre>` and `<textarea>` elements, where whitespace of texts is preserved.
+    preserve_whitespace_depth: usize,
 
     assignment_left: Option<Ident>,
     injecting_consts: Vec<VarDeclarator>,
@@ -64,28 +101,77 @@ where
 {
     pub fn new(options: Options, unresolved_mark: Mark, comments: Option<C>) -> Self {
         Self {
+            // there's no file system on `wasm32-unknown-unknown`, which the SWC plugin is built for
+            type_resolver: options
+                .type_resolver
+                .clone()
+                .filter(|_| !cfg!(all(target_arch = "wasm32", target_os = "unknown")))
+                .map(|options| TypeResolver::new(options, unresolved_mark)),
+            type_references: Default::default(),
+            imported_types: Default::default(),
+            type_params: Default::default(),
+            component_metadata: Default::default(),
             options,
             vue_imports: Default::default(),
             transform_on_helper: None,
 
-            define_component: None,
+            define_component: Default::default(),
+            vue_namespaces: Default::default(),
             interfaces: Default::default(),
             type_aliases: Default::default(),
+            type_alias_params: Default::default(),
+            resolving_type_aliases: Default::default(),
+            enums: Default::default(),
+            value_types: Default::default(),
+            text_bindings: Default::default(),
 
             unresolved_mark,
             comments,
+            file_name: None,
+            cwd: None,
+            source_map: None,
+            scope_id: None,
 
             pragma: None,
             slot_helper_ident: None,
             injecting_vars: Default::default(),
             slot_counter: 1,
             slot_flag_stack: Default::default(),
+            preserve_whitespace_depth: 0,
 
             assignment_left: None,
             injecting_consts: Default::default(),
         }
     }
 
+    /// Set path of current file which is used to infer name of default exported component.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Set current working directory which file paths in scope IDs and source locations are relative to.
+    pub fn with_cwd(mut self, cwd: impl Into<String>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    /// Set source map which is used to look up source locations when `devSourceLocations` is enabled.
+    pub fn with_source_map(mut self, source_map: Lrc<dyn SourceMapper>) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    /// Get metadata of components collected during transformation when `resolveType` is enabled,
+    /// which can be serialized as JSON.
+    pub fn component_metadata(&self) -> &[ComponentMetadata] {
+        &self.component_metadata
+    }
+
+    fn preserves_whitespace(&self) -> bool {
+        self.options.whitespace == Whitespace::Preserve || self.preserve_whitespace_depth > 0
+    }
+
     fn import_from_vue(&mut self, item: &'static str) -> Ident {
         self.vue_imports
             .entry(item)
@@ -108,10 +194,47 @@ where
         let mut directives = vec![];
         let AttrsTransformationResult {
             attrs,
-            patch_flags,
+            mut patch_flags,
             dynamic_props,
             slots,
-        } = self.transform_attrs(&jsx_element.opening.attrs, is_component, &mut directives);
+        } = self.transform_attrs(&jsx_element.opening, is_component, &mut directives);
+        let is_pre = !is_component
+            && matches!(&jsx_element.opening.name, JSXElementName::Ident(ident) if ident.sym == "pre" || ident.sym == "textarea");
+        let mut children = Cow::Borrowed(&*jsx_element.children);
+        if is_pre {
+            self.preserve_whitespace_depth += 1;
+            // leading newline of `<pre>` and `<textarea>` is ignored, just like HTML
+            if let Some(JSXElementChild::JSXText(jsx_text)) = jsx_element.children.first() {
+                let newline = ["\r\n", "\n"]
+                    .into_iter()
+                    .find(|newline| jsx_text.value.starts_with(newline));
+                if let Some(newline) = newline {
+                    children.to_mut()[0] = JSXElementChild::JSXText(JSXText {
+                        value: jsx_text.value[newline.len()..].into(),
+                        raw: jsx_text
+                            .raw
+                            .strip_prefix(newline)
+                            .unwrap_or_default()
+                            .into(),
+                        span: jsx_text.span,
+                    });
+                }
+            }
+        }
+        let text_children = if self.options.optimize && !is_component {
+            self.transform_text_children(&children)
+        } else {
+            None
+        };
+        let children = if let Some(text) = text_children {
+            patch_flags.insert(PatchFlags::TEXT);
+            text
+        } else {
+            self.transform_children(&children, is_component, slots)
+        };
+        if is_pre {
+            self.preserve_whitespace_depth -= 1;
+        }
         let mut vnode_call_args = vec![
             ExprOrSpread {
                 spread: None,
@@ -123,7 +246,7 @@ where
             },
             ExprOrSpread {
                 spread: None,
-                expr: Box::new(self.transform_children(&jsx_element.children, is_component, slots)),
+                expr: Box::new(children),
             },
         ];
         if self.options.optimize {
@@ -160,7 +283,7 @@ where
         }
 
         let create_vnode_call = Expr::Call(CallExpr {
-            span: DUMMY_SP,
+            span: self.pure_span(),
             callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
             args: vnode_call_args,
             ..Default::default()
@@ -170,7 +293,7 @@ where
             create_vnode_call
         } else {
             Expr::Call(CallExpr {
-                span: DUMMY_SP,
+                span: self.pure_span(),
                 callee: Callee::Expr(Box::new(Expr::Ident(
                     self.import_from_vue("withDirectives"),
                 ))),
@@ -235,7 +358,7 @@ where
         }
 
         Expr::Call(CallExpr {
-            span: DUMMY_SP,
+            span: self.pure_span(),
             callee: Callee::Expr(Box::new(Expr::Ident(self.get_pragma()))),
             args: vec![
                 ExprOrSpread {
@@ -276,7 +399,7 @@ where
                 } else if ident.to_id().1.has_mark(self.unresolved_mark) {
                     // for components that can't be resolved from current file
                     Expr::Call(CallExpr {
-                        span: DUMMY_SP,
+                        span: self.pure_span(),
                         callee: Callee::Expr(Box::new(Expr::Ident(
                             self.import_from_vue("resolveComponent"),
                         ))),
@@ -295,15 +418,91 @@ where
         }
     }
 
+    /// Check if attribute should be removed by `removeAttrs` or `removeAttrPatterns` option.
+    fn is_removed_attr(&self, name: &str) -> bool {
+        self.options.remove_attrs.iter().any(|attr| attr == name)
+            || self
+                .options
+                .remove_attr_patterns
+                .iter()
+                .any(|pattern| pattern.is_match(name))
+    }
+
+    /// Rename attribute by `attrAliases` option, then by `reactCompat` preset.
+    fn alias_attr_name<'n>(
+        &self,
+        name: Cow<'n, str>,
+        opening: &JSXOpeningElement,
+        is_component: bool,
+    ) -> Cow<'n, str> {
+        if let Some(alias) = self.options.attr_aliases.get(&*name) {
+            return Cow::from(alias.clone());
+        }
+        if !self.options.react_compat {
+            return name;
+        }
+        match &*name {
+            "className" => Cow::from("class"),
+            "htmlFor" => Cow::from("for"),
+            // components may declare `onDoubleClick` as their own event
+            "onDoubleClick" if !is_component => Cow::from("onDblclick"),
+            // React fires `onChange` on every keystroke of text inputs, just like `input` event
+            "onChange" if !is_component && util::is_text_input(opening) => Cow::from("onInput"),
+            _ => name,
+        }
+    }
+
+    /// Build attribute like `data-v-inspector="src/App.tsx:3:5"` for the given opening element.
+    fn build_source_location_attr(&self, span: Span) -> Option<(String, String)> {
+        let attr_name = match &self.options.dev_source_locations {
+            DevSourceLocations::Enabled(true) => "data-v-inspector",
+            DevSourceLocations::AttrName(name) => name,
+            DevSourceLocations::Enabled(false) => return None,
+        };
+        if span.is_dummy() {
+            return None;
+        }
+        let loc = self.source_map.as_ref()?.lookup_char_pos(span.lo);
+        let file_name = self
+            .file_name
+            .as_deref()
+            .map(|file_name| util::relative_file_path(file_name, self.cwd.as_deref()))
+            .unwrap_or_else(|| loc.file.name.to_string());
+        Some((
+            attr_name.to_string(),
+            format!("{file_name}:{}:{}", loc.line, loc.col.0 + 1),
+        ))
+    }
+
     fn transform_attrs<'a>(
         &mut self,
-        attrs: &'a [JSXAttrOrSpread],
+        opening: &'a JSXOpeningElement,
         is_component: bool,
         directives: &mut Vec<NormalDirective>,
     ) -> AttrsTransformationResult<'a> {
+        let attrs = opening
+            .attrs
+            .iter()
+            .filter(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(jsx_attr) if !is_directive(jsx_attr) => {
+                    !self.is_removed_attr(&util::jsx_attr_name(jsx_attr))
+                }
+                _ => true,
+            })
+            .collect::<Vec<_>>();
         let mut slots = None;
 
-        if attrs.is_empty() {
+        // static attributes injected to native elements, which don't affect patch flags
+        let mut injected_attrs = vec![];
+        if !is_component {
+            if let Some(scope_id) = &self.scope_id {
+                injected_attrs.push((scope_id.clone(), String::new()));
+            }
+            if let Some(attr) = self.build_source_location_attr(opening.span) {
+                injected_attrs.push(attr);
+            }
+        }
+        if attrs.is_empty() && injected_attrs.is_empty() {
             return AttrsTransformationResult {
                 attrs: Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
                 patch_flags: PatchFlags::empty(),
@@ -477,19 +676,26 @@ where
                         }
                     }
                     JSXAttrOrSpread::JSXAttr(jsx_attr) => {
-                        let attr_name = match &jsx_attr.name {
-                            JSXAttrName::Ident(ident) => Cow::from(&*ident.sym),
-                            JSXAttrName::JSXNamespacedName(name) => {
-                                Cow::from(format!("{}:{}", name.ns.sym, name.name.sym))
-                            }
-                        };
+                        let attr_name = self.alias_attr_name(
+                            util::jsx_attr_name(jsx_attr),
+                            opening,
+                            is_component,
+                        );
                         let attr_value = jsx_attr
                             .value
                             .as_ref()
                             .map(|value| match value {
-                                JSXAttrValue::Lit(Lit::Str(str)) => Box::new(Expr::Lit(Lit::Str(
-                                    quote_str!(util::transform_text(&str.value)),
-                                ))),
+                                JSXAttrValue::Lit(Lit::Str(str)) => {
+                                    let value = util::jsx_attr_str_value(str);
+                                    let value = if self.options.preserve_attr_whitespace
+                                        || self.options.whitespace == Whitespace::Preserve
+                                    {
+                                        value.into_owned()
+                                    } else {
+                                        util::transform_text(&value)
+                                    };
+                                    Box::new(Expr::Lit(Lit::Str(quote_str!(value))))
+                                }
                                 JSXAttrValue::Lit(..) => {
                                     unreachable!("JSX attribute value literal must be string")
                                 }
@@ -578,10 +784,24 @@ where
                         }
 
                         if let Expr::Object(object) = &*spread.expr {
+                            let object_props = object.props.iter().filter(|prop| {
+                                !prop
+                                    .as_prop()
+                                    .and_then(|prop| prop.as_key_value())
+                                    .and_then(|prop| match &prop.key {
+                                        PropName::Ident(ident) => Some(&ident.sym),
+                                        PropName::Str(str) => Some(&str.value),
+                                        _ => None,
+                                    })
+                                    .is_some_and(|name| self.is_removed_attr(name))
+                            });
                             if self.options.merge_props {
-                                merge_args.push(Expr::Object(object.clone()));
+                                merge_args.push(Expr::Object(ObjectLit {
+                                    span: object.span,
+                                    props: object_props.cloned().collect(),
+                                }));
                             } else {
-                                props.extend_from_slice(&object.props);
+                                props.extend(object_props.cloned());
                             }
                         } else if self.options.merge_props {
                             merge_args.push(*spread.expr.clone());
@@ -594,6 +814,13 @@ where
             },
         );
 
+        props.extend(injected_attrs.into_iter().map(|(name, value)| {
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(quote_str!(name)),
+                value: Box::new(Expr::Lit(Lit::Str(quote_str!(value)))),
+            })))
+        }));
+
         let expr = if !merge_args.is_empty() {
             if !props.is_empty() {
                 merge_args.push(Expr::Object(ObjectLit {
@@ -608,7 +835,7 @@ where
             match merge_args.as_slice() {
                 [expr] => expr.clone(),
                 _ => Expr::Call(CallExpr {
-                    span: DUMMY_SP,
+                    span: self.pure_span(),
                     callee: Callee::Expr(Box::new(Expr::Ident(self.import_from_vue("mergeProps")))),
                     args: merge_args
                         .into_iter()
@@ -624,13 +851,33 @@ where
             if let [PropOrSpread::Spread(SpreadElement { expr, .. })] = props.as_slice() {
                 *expr.clone()
             } else {
+                let mut props = if self.options.merge_props {
+                    util::dedupe_props(props)
+                } else {
+                    props
+                };
+                // `mergeProps` normalizes `class` and `style` at runtime, so they're only normalized here
+                props.iter_mut().for_each(|prop| {
+                    if let Some(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Str(Str { value: name, .. }),
+                        value,
+                    })) = prop.as_mut_prop().map(|prop| &mut **prop)
+                    {
+                        if name == "class" {
+                            *value = class_style::normalize_class(value.take(), || {
+                                (self.import_from_vue("normalizeClass"), self.pure_span())
+                            });
+                        } else if name == "style" {
+                            *value =
+                                class_style::normalize_style(value.take(), is_component, || {
+                                    (self.import_from_vue("normalizeStyle"), self.pure_span())
+                                });
+                        }
+                    }
+                });
                 Expr::Object(ObjectLit {
                     span: DUMMY_SP,
-                    props: if self.options.merge_props {
-                        util::dedupe_props(props)
-                    } else {
-                        props
-                    },
+                    props,
                 })
             }
         } else {
@@ -674,65 +921,60 @@ where
         is_component: bool,
         slots: Option<Box<Expr>>,
     ) -> Expr {
-        let elems = children
+        let mut elems = Vec::with_capacity(children.len());
+        // consecutive texts like `Hello {name}!` are merged into one text vnode
+        let optimize = self.options.optimize;
+        let preserve_whitespace = self.preserves_whitespace();
+        let children = children
             .iter()
-            .filter_map(|child| match child {
-                JSXElementChild::JSXText(jsx_text) => {
-                    self.transform_jsx_text(jsx_text).map(|expr| ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(expr),
-                    })
-                }
-                JSXElementChild::JSXExprContainer(JSXExprContainer {
-                    expr: JSXExpr::JSXEmptyExpr(..),
-                    ..
-                }) => None,
-                JSXElementChild::JSXExprContainer(JSXExprContainer {
-                    expr: JSXExpr::Expr(expr),
-                    ..
-                }) => {
-                    if self.options.optimize {
-                        match &**expr {
-                            Expr::Ident(ident)
-                                if !ident.to_id().1.has_mark(self.unresolved_mark) =>
-                            {
-                                self.slot_flag_stack.fill(SlotFlag::Dynamic);
-                            }
-                            _ => {}
-                        }
-                    }
-                    Some(ExprOrSpread {
+            .map(|child| (child, self.as_text_part(child, preserve_whitespace)))
+            .collect::<Vec<_>>();
+        for chunk in children.chunk_by(|(_, a), (_, b)| optimize && a.is_some() && b.is_some()) {
+            let parts = chunk
+                .iter()
+                .filter_map(|(_, part)| part.clone())
+                .flatten()
+                .collect::<Vec<_>>();
+            // runs without static text like `{foo}{bar}` are likely vnodes, so they're kept as-is
+            if parts.len() > 1
+                && parts
+                    .iter()
+                    .any(|part| matches!(part, TextPart::Static(..)))
+            {
+                let is_dynamic = parts
+                    .iter()
+                    .any(|part| matches!(part, TextPart::Dynamic(..)));
+                let mut args = vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(self.build_text_concat(parts)),
+                }];
+                if is_dynamic {
+                    args.push(ExprOrSpread {
                         spread: None,
-                        expr: expr.clone(),
-                    })
-                }
-                JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
-                    if self.options.optimize {
-                        match &**expr {
-                            Expr::Ident(ident)
-                                if !ident.to_id().1.has_mark(self.unresolved_mark) =>
-                            {
-                                self.slot_flag_stack.fill(SlotFlag::Dynamic);
-                            }
-                            _ => {}
-                        }
-                    }
-                    Some(ExprOrSpread {
-                        spread: Some(DUMMY_SP),
-                        expr: expr.clone(),
-                    })
+                        expr: Box::new(Expr::Lit(Lit::Num(Number {
+                            span: DUMMY_SP,
+                            value: PatchFlags::TEXT.bits() as f64,
+                            raw: None,
+                        }))),
+                    });
                 }
-                JSXElementChild::JSXElement(jsx_element) => Some(ExprOrSpread {
-                    spread: None,
-                    expr: Box::new(self.transform_jsx_element(jsx_element)),
-                }),
-                JSXElementChild::JSXFragment(jsx_fragment) => Some(ExprOrSpread {
+                elems.push(Some(ExprOrSpread {
                     spread: None,
-                    expr: Box::new(self.transform_jsx_fragment(jsx_fragment)),
-                }),
-            })
-            .map(Some)
-            .collect::<Vec<_>>();
+                    expr: Box::new(Expr::Call(CallExpr {
+                        span: self.pure_span(),
+                        callee: Callee::Expr(Box::new(Expr::Ident(
+                            self.import_from_vue("createTextVNode"),
+                        ))),
+                        args,
+                        ..Default::default()
+                    })),
+                }));
+            } else {
+                for (child, _) in chunk {
+                    elems.extend(self.transform_child(child).map(Some));
+                }
+            }
+        }
 
         let slot_flag = if self.options.optimize {
             self.slot_flag_stack.pop().unwrap_or(SlotFlag::Stable)
@@ -755,7 +997,7 @@ where
                         Expr::Cond(CondExpr {
                             span: DUMMY_SP,
                             test: Box::new(Expr::Call(CallExpr {
-                                span: DUMMY_SP,
+                                span: self.pure_span(),
                                 callee: Callee::Expr(Box::new(Expr::Ident(
                                     self.generate_slot_helper(),
                                 ))),
@@ -772,14 +1014,14 @@ where
                         self.wrap_children(elems, slot_flag, slots)
                     }
                 }
-                expr @ Expr::Call(..) if expr.span() != DUMMY_SP && is_component => {
+                expr @ Expr::Call(..) if !expr.span().is_dummy() && is_component => {
                     // the element was generated and doesn't have location information
                     if self.options.enable_object_slots {
                         let slot_ident = self.generate_unique_slot_ident();
                         Expr::Cond(CondExpr {
                             span: DUMMY_SP,
                             test: Box::new(Expr::Call(CallExpr {
-                                span: DUMMY_SP,
+                                span: self.pure_span(),
                                 callee: Callee::Expr(Box::new(Expr::Ident(
                                     self.generate_slot_helper(),
                                 ))),
@@ -860,6 +1102,60 @@ where
         }
     }
 
+    fn transform_child(&mut self, child: &JSXElementChild) -> Option<ExprOrSpread> {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                self.transform_jsx_text(jsx_text).map(|expr| ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(expr),
+                })
+            }
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => None,
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => {
+                if self.options.optimize {
+                    match &**expr {
+                        Expr::Ident(ident) if !ident.to_id().1.has_mark(self.unresolved_mark) => {
+                            self.slot_flag_stack.fill(SlotFlag::Dynamic);
+                        }
+                        _ => {}
+                    }
+                }
+                Some(ExprOrSpread {
+                    spread: None,
+                    expr: expr.clone(),
+                })
+            }
+            JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
+                if self.options.optimize {
+                    match &**expr {
+                        Expr::Ident(ident) if !ident.to_id().1.has_mark(self.unresolved_mark) => {
+                            self.slot_flag_stack.fill(SlotFlag::Dynamic);
+                        }
+                        _ => {}
+                    }
+                }
+                Some(ExprOrSpread {
+                    spread: Some(DUMMY_SP),
+                    expr: expr.clone(),
+                })
+            }
+            JSXElementChild::JSXElement(jsx_element) => Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(self.transform_jsx_element(jsx_element)),
+            }),
+            JSXElementChild::JSXFragment(jsx_fragment) => Some(ExprOrSpread {
+                spread: None,
+                expr: Box::new(self.transform_jsx_fragment(jsx_fragment)),
+            }),
+        }
+    }
+
     fn wrap_children(
         &self,
         elems: Vec<Option<ExprOrSpread>>,
@@ -931,12 +1227,16 @@ where
     }
 
     fn transform_jsx_text(&mut self, jsx_text: &JSXText) -> Option<Expr> {
-        let text = util::transform_text(&jsx_text.value);
+        let text = if self.preserves_whitespace() {
+            util::jsx_text_value(jsx_text).into_owned()
+        } else {
+            util::transform_text(&util::jsx_text_value(jsx_text))
+        };
         if text.is_empty() {
             None
         } else {
             Some(Expr::Call(CallExpr {
-                span: DUMMY_SP,
+                span: self.pure_span(),
                 callee: Callee::Expr(Box::new(Expr::Ident(
                     self.import_from_vue("createTextVNode"),
                 ))),
@@ -949,6 +1249,102 @@ where
         }
     }
 
+    /// Compile children of native element to a single string like `"Count: " + _toDisplayString(count)`
+    /// if all of them are texts and there's at least one dynamic text,
+    /// so runtime can patch `textContent` directly with `TEXT` patch flag.
+    fn transform_text_children(&mut self, children: &[JSXElementChild]) -> Option<Expr> {
+        let preserve_whitespace = self.preserves_whitespace();
+        let parts = children
+            .iter()
+            .map(|child| self.as_text_part(child, preserve_whitespace))
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if !parts
+            .iter()
+            .any(|part| matches!(part, TextPart::Dynamic(..)))
+        {
+            return None;
+        }
+
+        let text = self.build_text_concat(parts);
+        self.slot_flag_stack.pop();
+        Some(text)
+    }
+
+    /// Get text part of JSX child, returning `None` if it isn't text.
+    /// Whitespace-only texts and empty expressions are texts which render nothing.
+    fn as_text_part<'a>(
+        &self,
+        child: &'a JSXElementChild,
+        preserve_whitespace: bool,
+    ) -> Option<Option<TextPart<'a>>> {
+        match child {
+            JSXElementChild::JSXText(jsx_text) => {
+                let text = if preserve_whitespace {
+                    util::jsx_text_value(jsx_text).into_owned()
+                } else {
+                    util::transform_text(&util::jsx_text_value(jsx_text))
+                };
+                Some((!text.is_empty()).then_some(TextPart::Static(text)))
+            }
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::JSXEmptyExpr(..),
+                ..
+            }) => Some(None),
+            JSXElementChild::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) if util::is_text_like_expr(expr, &self.text_bindings) => {
+                Some(Some(TextPart::Dynamic(expr)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Concatenate text parts like `"Hello " + _toDisplayString(name)`.
+    fn build_text_concat(&mut self, parts: Vec<TextPart<'_>>) -> Expr {
+        // same as dynamic expression children in `transform_children`
+        if parts.iter().any(|part| {
+            matches!(part, TextPart::Dynamic(Expr::Ident(ident)) if !ident.ctxt.has_mark(self.unresolved_mark))
+        }) {
+            self.slot_flag_stack.fill(SlotFlag::Dynamic);
+        }
+
+        let mut exprs = Vec::with_capacity(parts.len());
+        for part in parts {
+            match (part, exprs.last_mut()) {
+                (TextPart::Static(text), Some(Expr::Lit(Lit::Str(last)))) => {
+                    *last = quote_str!(format!("{}{text}", last.value));
+                }
+                (TextPart::Static(text), _) => exprs.push(Expr::Lit(Lit::Str(quote_str!(text)))),
+                (TextPart::Dynamic(expr), _) => exprs.push(Expr::Call(CallExpr {
+                    span: self.pure_span(),
+                    callee: Callee::Expr(Box::new(Expr::Ident(
+                        self.import_from_vue("toDisplayString"),
+                    ))),
+                    args: vec![ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(expr.clone()),
+                    }],
+                    ..Default::default()
+                })),
+            }
+        }
+        exprs
+            .into_iter()
+            .reduce(|left, right| {
+                Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!(bin, "+"),
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            })
+            .unwrap_or_else(|| Expr::Lit(Lit::Str(quote_str!(""))))
+    }
+
     fn resolve_directive(&mut self, directive_name: &str, jsx_element: &JSXElement) -> Expr {
         match directive_name {
             "show" => Expr::Ident(self.import_from_vue("vShow")),
@@ -1027,6 +1423,19 @@ where
         }
     }
 
+    /// Create span with `#__PURE__` annotation if `pureAnnotations` option is enabled,
+    /// so minifiers can drop unused calls.
+    fn pure_span(&self) -> Span {
+        match &self.comments {
+            Some(comments) if self.options.pure_annotations => {
+                let span = Span::dummy_with_cmt();
+                comments.add_pure_comment(span.lo);
+                span
+            }
+            _ => DUMMY_SP,
+        }
+    }
+
     fn get_pragma(&mut self) -> Ident {
         self.pragma
             .as_ref()
@@ -1070,7 +1479,7 @@ where
                                     type_ann: None,
                                 }),
                                 init: Some(Box::new(Expr::Call(CallExpr {
-                                    span: DUMMY_SP,
+                                    span: self.pure_span(),
                                     callee: Callee::Expr(Box::new(Expr::Fn(FnExpr {
                                         ident: None,
                                         function: Box::new(Function {
@@ -1113,15 +1522,179 @@ where
         }
     }
 
+    /// Load types imported from other files, then they can be looked up like local types.
+    fn load_imported_types(&mut self) {
+        let (Some(type_resolver), Some(file_name)) = (&mut self.type_resolver, &self.file_name)
+        else {
+            return;
+        };
+        let mut decls = TypeDecls {
+            interfaces: &mut self.interfaces,
+            type_aliases: &mut self.type_aliases,
+            type_alias_params: &mut self.type_alias_params,
+            enums: &mut self.enums,
+        };
+        mem::take(&mut self.imported_types)
+            .into_iter()
+            .for_each(|(local, src, imported)| {
+                if let Some(key) =
+                    type_resolver.resolve(Path::new(file_name), &src, &imported, &mut decls)
+                {
+                    decls
+                        .type_aliases
+                        .insert(local, type_resolver::build_type_ref(key));
+                }
+            });
+    }
+
     fn is_define_component_call(&self, CallExpr { callee, .. }: &CallExpr) -> bool {
-        callee
-            .as_expr()
-            .and_then(|expr| expr.as_ident())
-            .and_then(|ident| {
-                self.define_component
-                    .map(|ctxt| ctxt == ident.ctxt && ident.sym == "defineComponent")
-            })
-            .unwrap_or_default()
+        match callee.as_expr().map(|expr| &**expr) {
+            Some(Expr::Ident(ident)) => self
+                .define_component
+                .contains(&(ident.sym.clone(), ident.ctxt)),
+            // `import * as Vue from 'vue'; Vue.defineComponent(...)`
+            Some(Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            })) => {
+                prop.sym == "defineComponent"
+                    && obj
+                        .as_ident()
+                        .map(|ident| {
+                            self.vue_namespaces
+                                .contains(&(ident.sym.clone(), ident.ctxt))
+                        })
+                        .unwrap_or_default()
+            }
+            _ => false,
+        }
+    }
+
+    /// Generate component options like `props` and `emits` from types of setup function.
+    /// It returns `true` if `props` option is generated.
+    fn resolve_component_options(&mut self, call_expr: &mut CallExpr) -> bool {
+        let Some(maybe_setup) = call_expr.args.first() else {
+            return false;
+        };
+
+        self.load_imported_types();
+
+        self.type_params = resolve_type::extract_type_params(maybe_setup);
+        let props_types = self.extract_props_type(maybe_setup);
+        let emits_types = self.extract_emits_type(maybe_setup);
+        let slots_type = self.extract_slots_type(maybe_setup);
+        let exposed = resolve_type::get_setup_context_param(maybe_setup)
+            .and_then(|context| util::extract_exposed(maybe_setup, context));
+        self.type_params.clear();
+        let props_resolved = props_types.is_some();
+        let mut metadata = ComponentMetadata {
+            name: None,
+            props: vec![],
+            emits: vec![],
+            slots: vec![],
+            exposed: exposed.unwrap_or_default(),
+            span: call_expr.span,
+        };
+        if let Some((prop_types, props)) = props_types {
+            inject_define_component_option(call_expr, "props", prop_types);
+            metadata.props = props;
+        }
+        if let Some((emits_type, emits)) = emits_types {
+            inject_define_component_option(call_expr, "emits", emits_type);
+            metadata.emits = emits;
+        }
+        if let Some((slots_type, slots)) = slots_type {
+            inject_define_component_option(call_expr, "slots", slots_type);
+            metadata.slots = slots;
+        }
+        self.component_metadata.push(metadata);
+        self.update_component_name(call_expr);
+
+        props_resolved
+    }
+
+    /// Sync component name in metadata with `name` option of `defineComponent` call,
+    /// since name may be inferred after props and emits are resolved.
+    fn update_component_name(&mut self, call_expr: &CallExpr) {
+        let Some(name) = util::find_component_name(call_expr) else {
+            return;
+        };
+        if let Some(metadata) = self
+            .component_metadata
+            .iter_mut()
+            .rev()
+            .find(|metadata| metadata.span == call_expr.span)
+        {
+            metadata.name = Some(name.to_string());
+        }
+    }
+
+    /// Give default exported functional component a name inferred from file name,
+    /// like `export default () => <div />` or `export default function () {}`,
+    /// so it can have `displayName` like other functional components.
+    fn name_default_exported_function(&self, module: &mut Module) {
+        let Some(name) = self
+            .file_name
+            .as_deref()
+            .and_then(util::infer_name_from_file)
+        else {
+            return;
+        };
+        let Some(index) = module.body.iter().position(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                ..
+            })) => util::is_functional_component_expr(expr),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        ident: None,
+                        function,
+                    }),
+                ..
+            })) => util::contains_jsx(&**function),
+            _ => false,
+        }) else {
+            return;
+        };
+        let ident = private_ident!(name);
+        match &mut module.body[index] {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(fn_expr),
+                ..
+            })) => {
+                fn_expr.ident = Some(ident);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                expr,
+                span,
+            })) => {
+                let span = *span;
+                let init = expr.take();
+                module.body.splice(
+                    index..=index,
+                    [
+                        ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                            span: DUMMY_SP,
+                            kind: VarDeclKind::Const,
+                            decls: vec![VarDeclarator {
+                                span: DUMMY_SP,
+                                name: Pat::Ident(ident.clone().into()),
+                                init: Some(init),
+                                definite: false,
+                            }],
+                            ..Default::default()
+                        })))),
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                            span,
+                            expr: Box::new(Expr::Ident(ident)),
+                        })),
+                    ],
+                );
+            }
+            _ => {}
+        }
     }
 }
 
@@ -1130,14 +1703,75 @@ where
     C: Comments,
 {
     fn visit_mut_module(&mut self, module: &mut Module) {
+        self.scope_id = match &self.options.scope_id {
+            Some(ScopeId::Id(id)) if id.starts_with("data-v-") => Some(id.clone()),
+            Some(ScopeId::Id(id)) => Some(format!("data-v-{id}")),
+            Some(ScopeId::Auto(true)) => self.file_name.as_deref().map(|file_name| {
+                format!(
+                    "data-v-{}",
+                    util::hash_file_path(file_name, self.cwd.as_deref())
+                )
+            }),
+            Some(ScopeId::Auto(false)) | None => None,
+        };
+        if self.options.resolve_type && self.options.type_resolver.is_some() {
+            if self.type_resolver.is_some() {
+                self.type_references = type_resolver::collect_type_references(module);
+            } else {
+                HANDLER.with(|handler| {
+                    handler.warn(
+                        "The `typeResolver` option isn't supported in SWC plugin, since it can't \
+                         access file system. Use the `swc-vue-jsx-visitor` crate instead.",
+                    );
+                });
+            }
+        }
         self.search_jsx_pragma(module.span);
         module
             .body
             .iter()
             .for_each(|item| self.search_jsx_pragma(item.span()));
 
+        // only functional components declared at module level have `displayName`,
+        // since functions declared inside render functions are recreated on each render
+        let functional_components = if self.options.resolve_type {
+            self.name_default_exported_function(module);
+            module
+                .body
+                .iter()
+                .enumerate()
+                .flat_map(|(index, item)| {
+                    let idents = match item {
+                        ModuleItem::Stmt(Stmt::Decl(decl))
+                        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                            decl, ..
+                        })) => util::collect_functional_components(decl),
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                            ExportDefaultDecl { decl, .. },
+                        )) => util::collect_default_exported_functional_component(decl)
+                            .into_iter()
+                            .collect(),
+                        _ => vec![],
+                    };
+                    idents.into_iter().map(move |ident| (index, ident))
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
         module.visit_mut_children_with(self);
 
+        functional_components
+            .into_iter()
+            .rev()
+            .for_each(|(index, ident)| {
+                module.body.insert(
+                    index + 1,
+                    ModuleItem::Stmt(util::build_display_name_assignment(ident)),
+                );
+            });
+
         if !self.injecting_consts.is_empty() {
             module.body.insert(
                 0,
@@ -1278,11 +1912,11 @@ where
                     arg: Some(ret.clone()),
                 }));
 
-                arrow_expr.body = Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                *arrow_expr.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
                     span: DUMMY_SP,
                     stmts,
                     ..Default::default()
-                }));
+                });
             }
         }
     }
@@ -1358,24 +1992,75 @@ where
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
         import_decl.visit_mut_children_with(self);
 
-        if import_decl.src.value != "vue" {
-            return;
-        }
+        let is_vue = import_decl.src.value == "vue";
+        import_decl
+            .specifiers
+            .iter()
+            .for_each(|specifier| match specifier {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    local, imported, ..
+                }) => {
+                    let imported = match imported {
+                        Some(ModuleExportName::Ident(ident)) => &ident.sym,
+                        Some(ModuleExportName::Str(str)) => &str.value,
+                        None => &local.sym,
+                    };
+                    if is_vue && imported == "defineComponent"
+                        || self
+                            .options
+                            .define_component_wrappers
+                            .iter()
+                            .any(|wrapper| imported == wrapper)
+                    {
+                        self.define_component
+                            .insert((local.sym.clone(), local.ctxt));
+                    }
+                }
+                ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) if is_vue => {
+                    self.vue_namespaces.insert((local.sym.clone(), local.ctxt));
+                }
+                _ => {}
+            });
 
-        let ctxt = import_decl.specifiers.iter().find_map(|specifier| {
-            if let ImportSpecifier::Named(ImportNamedSpecifier {
-                local,
-                imported: None,
-                ..
-            }) = specifier
-            {
-                (local.sym == "defineComponent").then_some(local.ctxt)
-            } else {
-                None
-            }
-        });
-        if let Some(ctxt) = ctxt {
-            self.define_component = Some(ctxt);
+        if self.options.resolve_type && self.type_resolver.is_some() && !is_vue {
+            self.imported_types.extend(
+                import_decl
+                    .specifiers
+                    .iter()
+                    .filter(|specifier| {
+                        let local = specifier.local();
+                        import_decl.type_only
+                            || matches!(
+                                specifier,
+                                ImportSpecifier::Named(ImportNamedSpecifier {
+                                    is_type_only: true,
+                                    ..
+                                })
+                            )
+                            || self
+                                .type_references
+                                .contains(&(local.sym.clone(), local.ctxt))
+                    })
+                    .filter_map(|specifier| match specifier {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            local, imported, ..
+                        }) => Some((
+                            (local.sym.clone(), local.ctxt),
+                            import_decl.src.value.clone(),
+                            match imported {
+                                Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                                Some(ModuleExportName::Str(str)) => str.value.clone(),
+                                None => local.sym.clone(),
+                            },
+                        )),
+                        ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => Some((
+                            (local.sym.clone(), local.ctxt),
+                            import_decl.src.value.clone(),
+                            Atom::from("default"),
+                        )),
+                        ImportSpecifier::Namespace(..) => None,
+                    }),
+            );
         }
     }
 
@@ -1397,20 +2082,54 @@ where
     fn visit_mut_ts_type_alias_decl(&mut self, ts_type_alias_decl: &mut TsTypeAliasDecl) {
         ts_type_alias_decl.visit_mut_children_with(self);
         if self.options.resolve_type {
-            self.type_aliases.insert(
-                (
-                    ts_type_alias_decl.id.sym.clone(),
-                    ts_type_alias_decl.id.ctxt,
-                ),
-                (*ts_type_alias_decl.type_ann).clone(),
+            let key = (
+                ts_type_alias_decl.id.sym.clone(),
+                ts_type_alias_decl.id.ctxt,
             );
+            if let Some(type_params) = &ts_type_alias_decl.type_params {
+                self.type_alias_params
+                    .insert(key.clone(), (**type_params).clone());
+            }
+            self.type_aliases
+                .insert(key, (*ts_type_alias_decl.type_ann).clone());
+        }
+    }
+
+    fn visit_mut_ts_enum_decl(&mut self, ts_enum_decl: &mut TsEnumDecl) {
+        ts_enum_decl.visit_mut_children_with(self);
+        if self.options.resolve_type {
+            let key = (ts_enum_decl.id.sym.clone(), ts_enum_decl.id.ctxt);
+            if let Some(ts_enum) = self.enums.get_mut(&key) {
+                ts_enum.members.extend_from_slice(&ts_enum_decl.members);
+            } else {
+                self.enums.insert(key, ts_enum_decl.clone());
+            }
+        }
+    }
+
+    fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+        fn_decl.visit_mut_children_with(self);
+        if self.options.resolve_type {
+            let ty = self.infer_type_from_function(&fn_decl.function);
+            self.value_types
+                .insert((fn_decl.ident.sym.clone(), fn_decl.ident.ctxt), ty);
+        }
+    }
+
+    fn visit_mut_class_decl(&mut self, class_decl: &mut ClassDecl) {
+        class_decl.visit_mut_children_with(self);
+        if self.options.resolve_type {
+            let ty = self.infer_type_from_class(&class_decl.class);
+            self.value_types
+                .insert((class_decl.ident.sym.clone(), class_decl.ident.ctxt), ty);
         }
     }
 
     fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
         call_expr.visit_mut_children_with(self);
 
-        if !self.options.resolve_type {
+        if !self.options.resolve_type && !self.options.props_destructure && self.scope_id.is_none()
+        {
             return;
         }
 
@@ -1418,17 +2137,119 @@ where
             return;
         }
 
-        let Some(maybe_setup) = call_expr.args.first() else {
+        let props_resolved = self.options.resolve_type && self.resolve_component_options(call_expr);
+
+        if self.options.props_destructure {
+            if let Some(ExprOrSpread {
+                expr: setup_fn,
+                spread: None,
+            }) = call_expr.args.first_mut()
+            {
+                props_destructure::transform_props_destructure(setup_fn, props_resolved, || {
+                    self.import_from_vue("createPropsRestProxy")
+                });
+            }
+        }
+
+        if let Some(scope_id) = &self.scope_id {
+            inject_define_component_option(
+                call_expr,
+                "__scopeId",
+                Expr::Lit(Lit::Str(quote_str!(scope_id.clone()))),
+            );
+        }
+    }
+
+    fn visit_mut_assign_expr(&mut self, assign_expr: &mut AssignExpr) {
+        assign_expr.visit_mut_children_with(self);
+
+        if !self.options.resolve_type {
             return;
+        }
+        let name = match &assign_expr.left {
+            AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => ident.sym.clone(),
+            AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                prop: MemberProp::Ident(ident),
+                ..
+            })) => ident.sym.clone(),
+            AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                prop: MemberProp::Computed(ComputedPropName { expr, .. }),
+                ..
+            })) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => str.value.clone(),
+                _ => return,
+            },
+            _ => return,
         };
+        let Expr::Call(call) = &mut *assign_expr.right else {
+            return;
+        };
+        if !self.is_define_component_call(call) {
+            return;
+        }
 
-        let props_types = self.extract_props_type(maybe_setup);
-        let emits_types = self.extract_emits_type(maybe_setup);
-        if let Some(prop_types) = props_types {
-            inject_define_component_option(call_expr, "props", prop_types);
+        inject_define_component_option(call, "name", Expr::Lit(Lit::Str(quote_str!(name))));
+        self.update_component_name(call);
+    }
+
+    fn visit_mut_export_default_expr(&mut self, export_default_expr: &mut ExportDefaultExpr) {
+        export_default_expr.visit_mut_children_with(self);
+
+        if !self.options.resolve_type {
+            return;
         }
-        if let Some(emits_type) = emits_types {
-            inject_define_component_option(call_expr, "emits", Expr::Array(emits_type));
+        let Some(name) = self
+            .file_name
+            .as_deref()
+            .and_then(util::infer_name_from_file)
+        else {
+            return;
+        };
+
+        match &mut *export_default_expr.expr {
+            Expr::Call(call) if self.is_define_component_call(call) => {
+                inject_define_component_option(call, "name", Expr::Lit(Lit::Str(quote_str!(name))));
+                self.update_component_name(call);
+            }
+            Expr::Object(object)
+                if object.props.iter().any(|prop| {
+                    prop.as_prop()
+                        .and_then(|prop| match &**prop {
+                            Prop::KeyValue(KeyValueProp { key, .. }) => key.as_ident(),
+                            Prop::Method(MethodProp { key, .. }) => key.as_ident(),
+                            _ => None,
+                        })
+                        .map(|ident| ident.sym == "setup" || ident.sym == "render")
+                        .unwrap_or_default()
+                }) =>
+            {
+                inject_object_option(object, "name", Expr::Lit(Lit::Str(quote_str!(name))));
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_var_decl(&mut self, var_decl: &mut VarDecl) {
+        var_decl.visit_mut_children_with(self);
+
+        if !self.options.optimize {
+            return;
+        }
+        // bindings are known to be primitive by type annotation or initializer of `const`
+        for declarator in &var_decl.decls {
+            let Pat::Ident(BindingIdent { id, type_ann }) = &declarator.name else {
+                continue;
+            };
+            let is_text = match (type_ann, declarator.init.as_deref()) {
+                (Some(type_ann), _) => util::is_text_type(&type_ann.type_ann),
+                (None, Some(init)) if var_decl.kind == VarDeclKind::Const => {
+                    util::is_text_like_expr(init, &self.text_bindings)
+                }
+                _ => false,
+            };
+            if is_text {
+                self.text_bindings.insert((id.sym.clone(), id.ctxt));
+            }
         }
     }
 
@@ -1441,6 +2262,19 @@ where
         let Pat::Ident(name) = &var_declarator.name else {
             return;
         };
+        if let Some(ty) = name
+            .type_ann
+            .as_ref()
+            .map(|type_ann| (*type_ann.type_ann).clone())
+            .or_else(|| {
+                var_declarator
+                    .init
+                    .as_ref()
+                    .and_then(|init| self.infer_type_from_expr(init))
+            })
+        {
+            self.value_types.insert((name.sym.clone(), name.ctxt), ty);
+        }
         let Some(Expr::Call(call)) = var_declarator.init.as_deref_mut() else {
             return;
         };
@@ -1453,10 +2287,23 @@ where
             "name",
             Expr::Lit(Lit::Str(quote_str!(name.sym.clone()))),
         );
+        self.update_component_name(call);
     }
 }
 
 fn inject_define_component_option(call: &mut CallExpr, name: &'static str, value: Expr) {
+    // `defineComponent({ ... })` accepts options object as the first argument
+    if let Some(ExprOrSpread {
+        spread: None,
+        expr: options,
+    }) = call.args.first_mut()
+    {
+        if let Expr::Object(object) = &mut **options {
+            inject_object_option(object, name, value);
+            return;
+        }
+    }
+
     let options = call.args.get_mut(1);
     if options
         .as_ref()
@@ -1467,22 +2314,7 @@ fn inject_define_component_option(call: &mut CallExpr, name: &'static str, value
     }
 
     match options.map(|options| &mut *options.expr) {
-        Some(Expr::Object(object)) => {
-            if !object.props.iter().any(|prop| {
-                prop.as_prop()
-                    .and_then(|prop| prop.as_key_value())
-                    .and_then(|key_value| key_value.key.as_ident())
-                    .map(|ident| ident.sym == name)
-                    .unwrap_or_default()
-            }) {
-                object
-                    .props
-                    .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                        key: PropName::Ident(quote_ident!(name)),
-                        value: Box::new(value),
-                    }))));
-            }
-        }
+        Some(Expr::Object(object)) => inject_object_option(object, name, value),
         Some(..) => {
             let expr = Expr::Object(ObjectLit {
                 props: vec![
@@ -1519,3 +2351,23 @@ fn inject_define_component_option(call: &mut CallExpr, name: &'static str, value
         }
     }
 }
+
+fn inject_object_option(object: &mut ObjectLit, name: &'static str, value: Expr) {
+    if !object.props.iter().any(|prop| {
+        prop.as_prop()
+            .and_then(|prop| match &**prop {
+                Prop::KeyValue(KeyValueProp { key, .. }) => key.as_ident(),
+                Prop::Method(MethodProp { key, .. }) => key.as_ident(),
+                _ => None,
+            })
+            .map(|ident| ident.sym == name)
+            .unwrap_or_default()
+    }) {
+        object
+            .props
+            .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(quote_ident!(name)),
+                value: Box::new(value),
+            }))));
+    }
+}
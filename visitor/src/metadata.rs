@@ -0,0 +1,34 @@
+use serde::Serialize;
+use swc_core::common::Span;
+
+/// Metadata of component defined by `defineComponent`,
+/// which is collected when `resolveType` option is enabled.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentMetadata {
+    /// Component name, either specified in component options or inferred.
+    pub name: Option<String>,
+    pub props: Vec<PropMetadata>,
+    pub emits: Vec<String>,
+    /// Slot names resolved from `SetupContext<E, S>`.
+    pub slots: Vec<String>,
+    /// Keys of object passed to `expose()` in setup function.
+    /// It's only collected for metadata and not injected as `expose` option,
+    /// because exposed values may not be accessible on component instance.
+    pub exposed: Vec<String>,
+    /// Span of `defineComponent` call, used to update component name after it's inferred.
+    #[serde(skip)]
+    pub(crate) span: Span,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropMetadata {
+    pub name: String,
+    /// Runtime types of prop like `["String", "Number"]`, where `None` means `null`.
+    pub types: Vec<Option<String>>,
+    pub required: bool,
+    /// Whether default value is statically specified.
+    /// Defaults passed as non-object expression to `mergeDefaults` can't be detected.
+    pub has_default: bool,
+}
@@ -2,7 +2,7 @@ use serde::{
     de::{Error, Unexpected, Visitor},
     Deserialize, Deserializer,
 };
-use std::{fmt, ops::Deref};
+use std::{collections::BTreeMap, fmt, ops::Deref};
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
@@ -14,6 +14,19 @@ pub struct Options {
     pub enable_object_slots: bool,
     pub pragma: Option<String>,
     pub resolve_type: bool,
+    pub define_component_wrappers: Vec<String>,
+    pub type_resolver: Option<TypeResolverOptions>,
+    pub emits_validators: bool,
+    pub props_destructure: bool,
+    pub whitespace: Whitespace,
+    pub preserve_attr_whitespace: bool,
+    pub scope_id: Option<ScopeId>,
+    pub dev_source_locations: DevSourceLocations,
+    pub remove_attrs: Vec<String>,
+    pub remove_attr_patterns: Vec<Regex>,
+    pub attr_aliases: BTreeMap<String, String>,
+    pub react_compat: bool,
+    pub pure_annotations: bool,
 }
 
 impl Default for Options {
@@ -26,10 +39,74 @@ impl Default for Options {
             enable_object_slots: true,
             pragma: None,
             resolve_type: false,
+            define_component_wrappers: Default::default(),
+            type_resolver: None,
+            emits_validators: false,
+            props_destructure: false,
+            whitespace: Whitespace::Condense,
+            preserve_attr_whitespace: false,
+            scope_id: None,
+            dev_source_locations: DevSourceLocations::Enabled(false),
+            remove_attrs: Default::default(),
+            remove_attr_patterns: Default::default(),
+            attr_aliases: Default::default(),
+            react_compat: false,
+            pure_annotations: true,
         }
     }
 }
 
+/// How to handle whitespace in JSX texts, which mirrors the `whitespace` option of Vue compiler.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Whitespace {
+    /// Trim whitespace around line breaks and join lines with a space, like React.
+    #[default]
+    Condense,
+    /// Keep whitespace as written.
+    Preserve,
+}
+
+/// Scope ID for scoped CSS, which is added as `data-v-xxx` attribute to native elements.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ScopeId {
+    /// Derive scope ID from hash of file path if `true`.
+    Auto(bool),
+    /// Specify scope ID explicitly, with or without the `data-v-` prefix.
+    Id(String),
+}
+
+/// Add source location like `src/App.tsx:3:5` as attribute to native elements,
+/// which can be used by element inspectors in development mode.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DevSourceLocations {
+    /// Use `data-v-inspector` as attribute name if `true`.
+    Enabled(bool),
+    /// Use custom attribute name.
+    AttrName(String),
+}
+
+impl Default for DevSourceLocations {
+    fn default() -> Self {
+        DevSourceLocations::Enabled(false)
+    }
+}
+
+/// Options for resolving types imported from other files.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TypeResolverOptions {
+    /// Directory of tsconfig, which `baseUrl` and `paths` are relative to.
+    /// Defaults to current directory.
+    pub tsconfig_dir: Option<String>,
+    /// Same as `compilerOptions.baseUrl` in tsconfig.
+    pub base_url: Option<String>,
+    /// Same as `compilerOptions.paths` in tsconfig.
+    pub paths: BTreeMap<String, Vec<String>>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Regex(regex::Regex);
 
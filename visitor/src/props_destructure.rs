@@ -0,0 +1,283 @@
+use fnv::FnvHashMap;
+use swc_core::{
+    common::{Span, Spanned, SyntaxContext, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::Atom,
+        utils::{private_ident, quote_ident, quote_str},
+        visit::{VisitMut, VisitMutWith},
+    },
+    plugin::errors::HANDLER,
+};
+
+/// Destructured props of setup function.
+struct DestructuredProps {
+    /// Local bindings and their corresponding prop names.
+    bindings: FnvHashMap<(Atom, SyntaxContext), Atom>,
+    /// Prop names in declaration order, which will be excluded from rest element.
+    keys: Vec<Atom>,
+    rest: Option<Ident>,
+    has_defaults: bool,
+}
+
+/// Rewrite destructured props parameter like `({ count }) => {}` to `(__props) => {}`,
+/// then references of `count` in function body will be rewritten to `__props.count`,
+/// so props are still reactive.
+///
+/// The `create_rest_proxy` callback is used to import `createPropsRestProxy` from Vue
+/// when there's rest element like `({ count, ...rest }) => {}`.
+pub(crate) fn transform_props_destructure(
+    setup_fn: &mut Expr,
+    defaults_resolved: bool,
+    create_rest_proxy: impl FnOnce() -> Ident,
+) {
+    let param = match setup_fn {
+        Expr::Arrow(arrow) => arrow.params.first_mut(),
+        Expr::Fn(fn_expr) => fn_expr
+            .function
+            .params
+            .first_mut()
+            .map(|param| &mut param.pat),
+        _ => None,
+    };
+    let Some(param) = param else {
+        return;
+    };
+    // `({ count }: Props = {}) => {}`
+    let pat = if let Pat::Assign(AssignPat { left, .. }) = param {
+        &mut **left
+    } else {
+        param
+    };
+    let Pat::Object(object_pat) = pat else {
+        return;
+    };
+    let Some(destructured) = collect_destructured_props(object_pat) else {
+        return;
+    };
+    if destructured.has_defaults && !defaults_resolved {
+        HANDLER.with(|handler| {
+            handler.span_err(
+                object_pat.span,
+                "Default values of destructured props can't be preserved without props type \
+                 annotation and `resolveType` option.",
+            );
+        });
+        return;
+    }
+
+    let type_ann = object_pat.type_ann.take();
+    let props = private_ident!("__props");
+    *pat = Pat::Ident(BindingIdent {
+        id: props.clone(),
+        type_ann,
+    });
+
+    let mut rewriter = PropsAccessRewriter {
+        bindings: destructured.bindings,
+        props: props.clone(),
+    };
+    let rest_decl = destructured
+        .rest
+        .map(|rest| build_rest_proxy_decl(rest, props, destructured.keys, create_rest_proxy()));
+    match setup_fn {
+        Expr::Arrow(arrow) => {
+            arrow.body.visit_mut_with(&mut rewriter);
+            if let Some(rest_decl) = rest_decl {
+                if let BlockStmtOrExpr::Expr(expr) = &*arrow.body {
+                    *arrow.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        stmts: vec![Stmt::Return(ReturnStmt {
+                            arg: Some(expr.clone()),
+                            span: DUMMY_SP,
+                        })],
+                        ..Default::default()
+                    });
+                }
+                if let BlockStmtOrExpr::BlockStmt(block) = &mut *arrow.body {
+                    block.stmts.insert(0, rest_decl);
+                }
+            }
+        }
+        Expr::Fn(fn_expr) => {
+            if let Some(body) = &mut fn_expr.function.body {
+                body.visit_mut_with(&mut rewriter);
+                if let Some(rest_decl) = rest_decl {
+                    body.stmts.insert(0, rest_decl);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_destructured_props(object_pat: &ObjectPat) -> Option<DestructuredProps> {
+    let mut destructured = DestructuredProps {
+        bindings: Default::default(),
+        keys: vec![],
+        rest: None,
+        has_defaults: false,
+    };
+    for prop in &object_pat.props {
+        match prop {
+            ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
+                destructured
+                    .bindings
+                    .insert((key.sym.clone(), key.ctxt), key.sym.clone());
+                destructured.keys.push(key.sym.clone());
+                destructured.has_defaults |= value.is_some();
+            }
+            ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                let name = match key {
+                    PropName::Ident(ident) => ident.sym.clone(),
+                    PropName::Str(str) => str.value.clone(),
+                    _ => {
+                        report_unsupported(key.span());
+                        return None;
+                    }
+                };
+                let pat = if let Pat::Assign(AssignPat { left, .. }) = &**value {
+                    destructured.has_defaults = true;
+                    &**left
+                } else {
+                    &**value
+                };
+                let Pat::Ident(local) = pat else {
+                    report_unsupported(pat.span());
+                    return None;
+                };
+                destructured
+                    .bindings
+                    .insert((local.sym.clone(), local.ctxt), name.clone());
+                destructured.keys.push(name);
+            }
+            ObjectPatProp::Rest(RestPat { arg, span, .. }) => {
+                if let Pat::Ident(ident) = &**arg {
+                    destructured.rest = Some(ident.id.clone());
+                } else {
+                    report_unsupported(*span);
+                    return None;
+                }
+            }
+        }
+    }
+    Some(destructured)
+}
+
+fn report_unsupported(span: Span) {
+    HANDLER.with(|handler| {
+        handler.span_err(
+            span,
+            "Nested destructuring or computed key isn't supported in props destructure.",
+        );
+    });
+}
+
+/// `const rest = _createPropsRestProxy(__props, ["count"])`
+fn build_rest_proxy_decl(rest: Ident, props: Ident, excluded: Vec<Atom>, helper: Ident) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            name: Pat::Ident(rest.into()),
+            init: Some(Box::new(Expr::Call(CallExpr {
+                callee: Callee::Expr(Box::new(Expr::Ident(helper))),
+                args: vec![
+                    ExprOrSpread {
+                        expr: Box::new(Expr::Ident(props)),
+                        spread: None,
+                    },
+                    ExprOrSpread {
+                        expr: Box::new(Expr::Array(ArrayLit {
+                            elems: excluded
+                                .into_iter()
+                                .map(|key| {
+                                    Some(ExprOrSpread {
+                                        expr: Box::new(Expr::Lit(Lit::Str(quote_str!(key)))),
+                                        spread: None,
+                                    })
+                                })
+                                .collect(),
+                            span: DUMMY_SP,
+                        })),
+                        spread: None,
+                    },
+                ],
+                ..Default::default()
+            }))),
+            definite: false,
+            span: DUMMY_SP,
+        }],
+        ..Default::default()
+    })))
+}
+
+struct PropsAccessRewriter {
+    bindings: FnvHashMap<(Atom, SyntaxContext), Atom>,
+    props: Ident,
+}
+
+impl PropsAccessRewriter {
+    fn build_member(&self, ident: &Ident) -> Option<Expr> {
+        let name = self.bindings.get(&(ident.sym.clone(), ident.ctxt))?;
+        Some(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(self.props.clone())),
+            prop: if Ident::verify_symbol(name).is_ok() {
+                MemberProp::Ident(quote_ident!(name.clone()))
+            } else {
+                MemberProp::Computed(ComputedPropName {
+                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name.clone())))),
+                    span: DUMMY_SP,
+                })
+            },
+            span: ident.span,
+        }))
+    }
+}
+
+impl VisitMut for PropsAccessRewriter {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Ident(ident) = expr {
+            if let Some(member) = self.build_member(ident) {
+                *expr = member;
+            }
+        } else {
+            expr.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop {
+            if let Some(member) = self.build_member(ident) {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ident.clone().into()),
+                    value: Box::new(member),
+                });
+            }
+        } else {
+            prop.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_update_expr(&mut self, update_expr: &mut UpdateExpr) {
+        if let Expr::Ident(ident) = &*update_expr.arg {
+            if self.bindings.contains_key(&(ident.sym.clone(), ident.ctxt)) {
+                HANDLER.with(|handler| {
+                    handler.span_err(ident.span, "Destructured props are readonly.");
+                });
+            }
+        } else {
+            update_expr.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_simple_assign_target(&mut self, target: &mut SimpleAssignTarget) {
+        if let SimpleAssignTarget::Ident(ident) = target {
+            if self.bindings.contains_key(&(ident.sym.clone(), ident.ctxt)) {
+                HANDLER.with(|handler| {
+                    handler.span_err(ident.span, "Destructured props are readonly.");
+                });
+            }
+        } else {
+            target.visit_mut_children_with(self);
+        }
+    }
+}
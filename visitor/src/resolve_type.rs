@@ -1,12 +1,14 @@
-use crate::VueJsxTransformVisitor;
+use crate::{metadata::PropMetadata, VueJsxTransformVisitor};
+use fnv::FnvHashMap;
 use indexmap::{IndexMap, IndexSet};
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::RefCell, ops::Deref};
 use swc_core::{
-    common::{comments::Comments, EqIgnoreSpan, Span, Spanned, DUMMY_SP},
+    common::{comments::Comments, EqIgnoreSpan, Span, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
         atoms::{atom, Atom},
         utils::{quote_ident, quote_str},
+        visit::{VisitMut, VisitMutWith},
     },
     plugin::errors::HANDLER,
 };
@@ -18,8 +20,41 @@ enum RefinedTsTypeElement {
     CallSignature(TsCallSignatureDecl),
 }
 
+/// Max depth of nested type aliases, for recursive types that never repeat themselves
+/// like `type Nested<T> = T extends string ? Nested<[T]> : T`.
+const MAX_TYPE_ALIAS_DEPTH: usize = 32;
+
+/// Max number of keys expanded from a template literal type,
+/// since the number of combinations grows exponentially with interpolated unions.
+const MAX_TEMPLATE_LITERAL_KEYS: usize = 1000;
+
+pub(crate) type ResolvingTypeAliases =
+    RefCell<Vec<((Atom, SyntaxContext), Option<TsTypeParamInstantiation>)>>;
+
+/// Resolved type alias, which is kept in resolving stack until it's dropped.
+struct ResolvedTypeAlias<'a> {
+    ty: Cow<'a, TsType>,
+    resolving: Option<&'a ResolvingTypeAliases>,
+}
+
+impl Deref for ResolvedTypeAlias<'_> {
+    type Target = TsType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ty
+    }
+}
+
+impl Drop for ResolvedTypeAlias<'_> {
+    fn drop(&mut self) {
+        if let Some(resolving) = self.resolving {
+            resolving.borrow_mut().pop();
+        }
+    }
+}
+
 struct PropIr {
-    types: IndexSet<Option<Atom>>,
+    types: IndexSet<RuntimeType>,
     required: bool,
 }
 
@@ -27,27 +62,57 @@ impl<C> VueJsxTransformVisitor<C>
 where
     C: Comments,
 {
-    pub(crate) fn extract_props_type(&mut self, setup_fn: &ExprOrSpread) -> Option<Expr> {
-        let mut defaults = None;
-        let first_param_type = if let ExprOrSpread { expr, spread: None } = setup_fn {
+    pub(crate) fn extract_props_type(
+        &mut self,
+        setup_fn: &ExprOrSpread,
+    ) -> Option<(Expr, Vec<PropMetadata>)> {
+        let first_param = if let ExprOrSpread { expr, spread: None } = setup_fn {
             match &**expr {
-                Expr::Arrow(arrow) => arrow.params.first().and_then(|param| {
-                    if let Pat::Assign(AssignPat { right, .. }) = param {
-                        defaults = Some(&**right);
-                    }
-                    extract_type_ann_from_pat(param)
-                }),
-                Expr::Fn(fn_expr) => fn_expr.function.params.first().and_then(|param| {
-                    if let Pat::Assign(AssignPat { right, .. }) = &param.pat {
-                        defaults = Some(&**right);
-                    }
-                    extract_type_ann_from_pat(&param.pat)
-                }),
+                Expr::Arrow(arrow) => arrow.params.first(),
+                Expr::Fn(fn_expr) => fn_expr.function.params.first().map(|param| &param.pat),
                 _ => None,
             }?
         } else {
             return None;
         };
+        let first_param_type = extract_type_ann_from_pat(first_param)?;
+        let (pat, defaults) = if let Pat::Assign(AssignPat { left, right, .. }) = first_param {
+            (&**left, Some(&**right))
+        } else {
+            (first_param, None)
+        };
+
+        // defaults from destructuring like `({ size = 'md' }: Props) => {}`
+        let mut destructured_defaults = vec![];
+        if let Pat::Object(ObjectPat { props, span, .. }) = pat {
+            if !self.options.props_destructure {
+                HANDLER.with(|handler| {
+                    handler.span_warn(
+                        *span,
+                        "Destructured props lose reactivity, use `props.xxx` to access props \
+                         instead or enable `propsDestructure` option.",
+                    );
+                });
+            }
+            destructured_defaults.extend(props.iter().filter_map(|prop| match prop {
+                ObjectPatProp::Assign(AssignPatProp {
+                    key,
+                    value: Some(value),
+                    ..
+                }) => Some((
+                    Cow::Owned(PropName::Ident(key.id.clone().into())),
+                    build_default_value(value),
+                )),
+                ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+                    if let Pat::Assign(AssignPat { right, .. }) = &**value {
+                        try_unwrap_lit_prop_name(key).map(|key| (key, build_default_value(right)))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }));
+        }
 
         enum Defaults<'n> {
             Static(Vec<(Cow<'n, PropName>, Expr)>),
@@ -74,25 +139,8 @@ where
                                     }),
                                 )),
                                 Prop::KeyValue(KeyValueProp { key, value }) => {
-                                    try_unwrap_lit_prop_name(key).map(|key| {
-                                        (
-                                            key,
-                                            if value.is_lit() {
-                                                (**value).clone()
-                                            } else {
-                                                Expr::Arrow(ArrowExpr {
-                                                    params: vec![],
-                                                    body: Box::new(BlockStmtOrExpr::Expr(
-                                                        value.clone(),
-                                                    )),
-                                                    is_async: false,
-                                                    is_generator: false,
-                                                    span: DUMMY_SP,
-                                                    ..Default::default()
-                                                })
-                                            },
-                                        )
-                                    })
+                                    try_unwrap_lit_prop_name(key)
+                                        .map(|key| (key, build_default_value(value)))
                                 }
                                 Prop::Getter(GetterProp {
                                     key,
@@ -143,17 +191,20 @@ where
 
         Some(match defaults {
             Some(Defaults::Static(props)) => {
-                Expr::Object(self.build_props_type(first_param_type, Some(props)))
+                destructured_defaults.extend(props);
+                let (object, metadata) =
+                    self.build_props_type(first_param_type, Some(destructured_defaults));
+                (Expr::Object(object), metadata)
             }
             Some(Defaults::Dynamic(expr)) => {
                 let merge_defaults = self.import_from_vue("mergeDefaults");
-                Expr::Call(CallExpr {
+                let (object, metadata) =
+                    self.build_props_type(first_param_type, Some(destructured_defaults));
+                let call = Expr::Call(CallExpr {
                     callee: Callee::Expr(Box::new(Expr::Ident(merge_defaults))),
                     args: vec![
                         ExprOrSpread {
-                            expr: Box::new(Expr::Object(
-                                self.build_props_type(first_param_type, None),
-                            )),
+                            expr: Box::new(Expr::Object(object)),
                             spread: None,
                         },
                         ExprOrSpread {
@@ -169,9 +220,14 @@ where
                         DUMMY_SP
                     },
                     ..Default::default()
-                })
+                });
+                (call, metadata)
+            }
+            None => {
+                let (object, metadata) =
+                    self.build_props_type(first_param_type, Some(destructured_defaults));
+                (Expr::Object(object), metadata)
             }
-            None => Expr::Object(self.build_props_type(first_param_type, None)),
         })
     }
 
@@ -179,7 +235,7 @@ where
         &self,
         TsTypeAnn { type_ann, .. }: &TsTypeAnn,
         defaults: Option<Vec<(Cow<PropName>, Expr)>>,
-    ) -> ObjectLit {
+    ) -> (ObjectLit, Vec<PropMetadata>) {
         let mut props = Vec::with_capacity(3);
         self.resolve_type_elements(type_ann, &mut props);
 
@@ -200,7 +256,7 @@ where
                             self.infer_runtime_type(&type_ann.type_ann)
                         } else {
                             let mut types = IndexSet::with_capacity(1);
-                            types.insert(None);
+                            types.insert(RuntimeType::Null);
                             types
                         };
                         if let Some((_, ir)) = irs
@@ -232,7 +288,7 @@ where
                             self.infer_runtime_type(&type_ann.type_ann)
                         } else {
                             let mut types = IndexSet::with_capacity(1);
-                            types.insert(None);
+                            types.insert(RuntimeType::Null);
                             types
                         };
                         if let Some((_, ir)) = irs
@@ -257,7 +313,7 @@ where
                         ..
                     }) => {
                         let prop_name = extract_prop_name(*key, computed);
-                        let ty = Some(atom!("Function"));
+                        let ty = RuntimeType::Ctor(atom!("Function"));
                         if let Some((_, ir)) = irs
                             .iter_mut()
                             .find(|(key, _)| prop_name.eq_ignore_span(key))
@@ -284,19 +340,33 @@ where
             },
         );
 
-        ObjectLit {
+        let mut metadata = Vec::with_capacity(irs.len());
+        let object = ObjectLit {
             props: irs
                 .into_iter()
                 .map(|(prop_name, mut ir)| {
+                    let skip_check = normalize_runtime_types(&mut ir.types);
+                    let is_function = ir.types.contains(&RuntimeType::Ctor(atom!("Function")));
+                    let is_exact_function = is_function && ir.types.len() == 1;
+                    let mut prop_metadata =
+                        prop_name_to_atom(&prop_name).map(|name| PropMetadata {
+                            name: name.to_string(),
+                            types: ir
+                                .types
+                                .iter()
+                                .map(|ty| match ty {
+                                    RuntimeType::Ctor(ty) => Some(ty.to_string()),
+                                    RuntimeType::Null | RuntimeType::Unknown => None,
+                                })
+                                .collect(),
+                            required: ir.required,
+                            has_default: false,
+                        });
                     let mut props = vec![
                         PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                             key: PropName::Ident(quote_ident!("type")),
                             value: Box::new(if ir.types.len() == 1 {
-                                if let Some(ty) = ir.types.pop().unwrap() {
-                                    Expr::Ident(quote_ident!(ty).into())
-                                } else {
-                                    Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))
-                                }
+                                ir.types.pop().unwrap().into_expr()
                             } else {
                                 Expr::Array(ArrayLit {
                                     elems: ir
@@ -304,11 +374,7 @@ where
                                         .into_iter()
                                         .map(|ty| {
                                             Some(ExprOrSpread {
-                                                expr: Box::new(if let Some(ty) = ty {
-                                                    Expr::Ident(quote_ident!(ty).into())
-                                                } else {
-                                                    Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))
-                                                }),
+                                                expr: Box::new(ty.into_expr()),
                                                 spread: None,
                                             })
                                         })
@@ -325,6 +391,15 @@ where
                             }))),
                         }))),
                     ];
+                    if skip_check {
+                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(quote_ident!("skipCheck")),
+                            value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                                value: true,
+                                span: DUMMY_SP,
+                            }))),
+                        }))));
+                    }
                     if let Some((_, default)) = defaults.iter().flatten().find(|(name, _)| {
                         name.eq_ignore_span(&prop_name)
                             || if let (
@@ -341,11 +416,33 @@ where
                                 false
                             }
                     }) {
+                        let default = if is_function {
+                            unwrap_default_factory(default)
+                        } else {
+                            default
+                        };
                         props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                             key: PropName::Ident(quote_ident!("default")),
                             value: Box::new(default.clone()),
                         }))));
+                        // Vue calls function default unless prop type is exactly `Function`,
+                        // so it must be skipped when `Function` is in union
+                        if is_function && !is_exact_function && !default.is_lit() {
+                            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("skipFactory")),
+                                    value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                                        value: true,
+                                        span: DUMMY_SP,
+                                    }))),
+                                },
+                            ))));
+                        }
+                        if let Some(prop_metadata) = &mut prop_metadata {
+                            prop_metadata.has_default = true;
+                        }
                     }
+                    metadata.extend(prop_metadata);
                     PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                         key: prop_name,
                         value: Box::new(Expr::Object(ObjectLit {
@@ -356,7 +453,8 @@ where
                 })
                 .collect(),
             span: DUMMY_SP,
-        }
+        };
+        (object, metadata)
     }
 
     fn resolve_type_elements(&self, ty: &TsType, props: &mut Vec<RefinedTsTypeElement>) {
@@ -393,8 +491,18 @@ where
                 ..
             }) => {
                 let key = (ident.sym.clone(), ident.ctxt);
-                if let Some(aliased) = self.type_aliases.get(&key) {
-                    self.resolve_type_elements(aliased, props);
+                if let Some(type_param) = self.type_params.get(&key) {
+                    if let Some(constraint) = type_param {
+                        self.resolve_type_elements(constraint, props);
+                    } else {
+                        HANDLER.with(|handler| {
+                            handler
+                                .span_err(*span, "Unconstrained generic type can't be resolved.");
+                        });
+                    }
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    self.resolve_type_elements(&aliased, props);
                 } else if let Some(TsInterfaceDecl {
                     extends,
                     body: TsInterfaceBody { body, .. },
@@ -478,6 +586,34 @@ where
                                 }));
                             }
                         }
+                        "Readonly" => {
+                            if let Some(param) = type_params
+                                .as_deref()
+                                .and_then(|params| params.params.first())
+                            {
+                                self.resolve_type_elements(param, props);
+                            }
+                        }
+                        "Record" => {
+                            if let Some((keys, value)) = type_params
+                                .as_deref()
+                                .and_then(|params| params.params.first().zip(params.params.get(1)))
+                            {
+                                props.extend(
+                                    self.resolve_string_or_union_strings(keys).into_iter().map(
+                                        |key| {
+                                            RefinedTsTypeElement::Property(
+                                                build_property_signature(
+                                                    key,
+                                                    Some((**value).clone()),
+                                                    false,
+                                                ),
+                                            )
+                                        },
+                                    ),
+                                );
+                            }
+                        }
                         "Pick" => {
                             if let Some((object, keys)) = type_params
                                 .as_deref()
@@ -536,6 +672,19 @@ where
                                 }));
                             }
                         }
+                        "ReturnType" | "InstanceType" => {
+                            if let Some(ty) = type_params
+                                .as_deref()
+                                .and_then(|params| params.params.first())
+                                .and_then(|param| self.resolve_return_type(&ident.sym, param))
+                            {
+                                self.resolve_type_elements(&ty, props);
+                            } else {
+                                HANDLER.with(|handler| {
+                                    handler.span_err(*span, "Unresolvable type.");
+                                });
+                            }
+                        }
                         _ => {
                             HANDLER.with(|handler| {
                                 handler.span_err(
@@ -578,9 +727,32 @@ where
                 }));
             }
             TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. })
-            | TsType::TsOptionalType(TsOptionalType { type_ann, .. }) => {
+            | TsType::TsOptionalType(TsOptionalType { type_ann, .. })
+            | TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::ReadOnly,
+                type_ann,
+                ..
+            }) => {
                 self.resolve_type_elements(type_ann, props);
             }
+            TsType::TsMappedType(mapped) => self.resolve_mapped_type(mapped, props),
+            TsType::TsTypeQuery(query) => {
+                if let Some(ty) = self.resolve_type_query(query) {
+                    self.resolve_type_elements(&ty, props);
+                } else {
+                    HANDLER.with(|handler| {
+                        handler.span_err(query.span, "Unresolvable type.");
+                    });
+                }
+            }
+            TsType::TsConditionalType(conditional) => {
+                if let Some(ty) = self.evaluate_conditional_type(conditional) {
+                    self.resolve_type_elements(ty, props);
+                } else {
+                    self.resolve_type_elements(&conditional.true_type, props);
+                    self.resolve_type_elements(&conditional.false_type, props);
+                }
+            }
             _ => HANDLER.with(|handler| {
                 handler.span_err(ty.span(), "Unresolvable type.");
             }),
@@ -611,18 +783,64 @@ where
                 }),
             TsType::TsTypeRef(TsTypeRef {
                 type_name: TsEntityName::Ident(ident),
+                type_params,
                 ..
             }) => {
-                if let Some(aliased) = self.type_aliases.get(&(ident.sym.clone(), ident.ctxt)) {
-                    self.resolve_string_or_union_strings(aliased)
+                let key = (ident.sym.clone(), ident.ctxt);
+                if let Some(Some(constraint)) = self.type_params.get(&key) {
+                    self.resolve_string_or_union_strings(constraint)
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    self.resolve_string_or_union_strings(&aliased)
+                } else if let Some(TsEnumDecl { members, .. }) = self.enums.get(&key) {
+                    members
+                        .iter()
+                        .filter_map(enum_member_string_value)
+                        .collect()
                 } else if ident.ctxt.has_mark(self.unresolved_mark) {
-                    HANDLER.with(|handler| {
-                        handler.span_err(
-                            ty.span(),
-                            "Unresolvable type reference or unsupported built-in utility type.",
-                        );
-                    });
-                    vec![]
+                    match (
+                        &*ident.sym,
+                        type_params
+                            .as_deref()
+                            .and_then(|params| params.params.first().zip(params.params.get(1))),
+                    ) {
+                        ("Exclude", Some((source, excluded))) => {
+                            let excluded = self.resolve_string_or_union_strings(excluded);
+                            self.resolve_string_or_union_strings(source)
+                                .into_iter()
+                                .filter(|key| !excluded.contains(key))
+                                .collect()
+                        }
+                        ("Extract", Some((source, extracted))) => {
+                            let extracted = self.resolve_string_or_union_strings(extracted);
+                            self.resolve_string_or_union_strings(source)
+                                .into_iter()
+                                .filter(|key| extracted.contains(key))
+                                .collect()
+                        }
+                        ("Uppercase" | "Lowercase" | "Capitalize" | "Uncapitalize", _) => {
+                            let Some(param) = type_params
+                                .as_deref()
+                                .and_then(|params| params.params.first())
+                            else {
+                                return vec![];
+                            };
+                            self.resolve_string_or_union_strings(param)
+                                .into_iter()
+                                .map(|str| manipulate_string(&ident.sym, &str))
+                                .collect()
+                        }
+                        _ => {
+                            HANDLER.with(|handler| {
+                                handler.span_err(
+                                    ty.span(),
+                                    "Unresolvable type reference or unsupported built-in utility \
+                                     type.",
+                                );
+                            });
+                            vec![]
+                        }
+                    }
                 } else {
                     HANDLER.with(|handler| {
                         handler.span_err(ty.span(), "Types from other modules can't be resolved.");
@@ -630,6 +848,81 @@ where
                     vec![]
                 }
             }
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::TsQualifiedName(qualified_name),
+                ..
+            }) => self
+                .resolve_enum_member(qualified_name)
+                .and_then(enum_member_string_value)
+                .into_iter()
+                .collect(),
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::KeyOf,
+                type_ann,
+                ..
+            }) => {
+                if let Some(TsEnumDecl { members, .. }) =
+                    self.resolve_enum_from_type_query(type_ann)
+                {
+                    members
+                        .iter()
+                        .map(|member| member.id.as_ref().clone())
+                        .collect()
+                } else {
+                    let mut elements = vec![];
+                    self.resolve_type_elements(type_ann, &mut elements);
+                    elements
+                        .iter()
+                        .filter_map(|element| element.key())
+                        .cloned()
+                        .collect()
+                }
+            }
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Tpl(TsTplLitType { types, quasis, .. }),
+                ..
+            }) => {
+                // keys like `data-${string}` can't be enumerated, which work like index signature
+                if types.iter().any(|ty| self.is_unbounded_key_type(ty)) {
+                    return vec![];
+                }
+                // expand template literal type to all combinations of its interpolated types,
+                // for example, `${'a' | 'b'}-${'c' | 'd'}` becomes 'a-c' | 'a-d' | 'b-c' | 'b-d'
+                let mut strings = vec![String::new()];
+                for (i, quasi) in quasis.iter().enumerate() {
+                    let quasi = quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+                    strings.iter_mut().for_each(|str| str.push_str(quasi));
+                    if let Some(ty) = types.get(i) {
+                        let parts = self.resolve_string_or_union_strings(ty);
+                        if strings.len() * parts.len() > MAX_TEMPLATE_LITERAL_KEYS {
+                            HANDLER.with(|handler| {
+                                handler.span_err(
+                                    ty.span(),
+                                    "Template literal type produces too many keys.",
+                                );
+                            });
+                            return vec![];
+                        }
+                        strings = strings
+                            .iter()
+                            .flat_map(|str| parts.iter().map(move |part| format!("{str}{part}")))
+                            .collect();
+                    }
+                }
+                strings.into_iter().map(Atom::from).collect()
+            }
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.resolve_string_or_union_strings(type_ann)
+            }
+            TsType::TsConditionalType(conditional) => {
+                if let Some(ty) = self.evaluate_conditional_type(conditional) {
+                    self.resolve_string_or_union_strings(ty)
+                } else {
+                    let mut strings = self.resolve_string_or_union_strings(&conditional.true_type);
+                    strings.extend(self.resolve_string_or_union_strings(&conditional.false_type));
+                    strings
+                }
+            }
             _ => {
                 HANDLER
                     .with(|handler| handler.span_err(ty.span(), "Unsupported type as index key."));
@@ -638,6 +931,55 @@ where
         }
     }
 
+    /// Check if type used in template literal type can't be enumerated, like `string` or `number`.
+    fn is_unbounded_key_type(&self, ty: &TsType) -> bool {
+        match ty {
+            TsType::TsKeywordType(TsKeywordType { kind, .. }) => matches!(
+                kind,
+                TsKeywordTypeKind::TsStringKeyword
+                    | TsKeywordTypeKind::TsNumberKeyword
+                    | TsKeywordTypeKind::TsBigIntKeyword
+                    | TsKeywordTypeKind::TsAnyKeyword
+            ),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                TsUnionType { types, .. },
+            )) => types.iter().any(|ty| self.is_unbounded_key_type(ty)),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.is_unbounded_key_type(type_ann)
+            }
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Tpl(TsTplLitType { types, .. }),
+                ..
+            }) => types.iter().any(|ty| self.is_unbounded_key_type(ty)),
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ident),
+                type_params,
+                ..
+            }) => {
+                let key = (ident.sym.clone(), ident.ctxt);
+                if let Some(Some(constraint)) = self.type_params.get(&key) {
+                    self.is_unbounded_key_type(constraint)
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    self.is_unbounded_key_type(&aliased)
+                } else if ident.ctxt.has_mark(self.unresolved_mark)
+                    && matches!(
+                        &*ident.sym,
+                        "Uppercase" | "Lowercase" | "Capitalize" | "Uncapitalize"
+                    )
+                {
+                    type_params
+                        .as_deref()
+                        .and_then(|params| params.params.first())
+                        .is_some_and(|ty| self.is_unbounded_key_type(ty))
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
     fn resolve_indexed_access(&self, obj: &TsType, index: &TsType) -> Option<TsType> {
         match obj {
             TsType::TsTypeRef(TsTypeRef {
@@ -646,8 +988,13 @@ where
                 ..
             }) => {
                 let key = (ident.sym.clone(), ident.ctxt);
-                if let Some(aliased) = self.type_aliases.get(&key) {
-                    self.resolve_indexed_access(aliased, index)
+                if let Some(type_param) = self.type_params.get(&key) {
+                    type_param
+                        .as_ref()
+                        .and_then(|constraint| self.resolve_indexed_access(constraint, index))
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    self.resolve_indexed_access(&aliased, index)
                 } else if let Some(interface) = self.interfaces.get(&key) {
                     let mut properties = match index {
                         TsType::TsKeywordType(TsKeywordType {
@@ -772,13 +1119,22 @@ where
                         ))
                     }
                 } else if ident.ctxt.has_mark(self.unresolved_mark) {
-                    if ident.sym == "Array" {
-                        type_params
+                    match &*ident.sym {
+                        "Array" => type_params
                             .as_ref()
                             .and_then(|params| params.params.first())
-                            .map(|ty| (**ty).clone())
-                    } else {
-                        None
+                            .map(|ty| (**ty).clone()),
+                        "Partial" | "Required" | "Readonly" | "Record" | "Pick" | "Omit" => {
+                            let mut elements = vec![];
+                            self.resolve_type_elements(obj, &mut elements);
+                            self.resolve_indexed_access(&build_type_lit(elements), index)
+                        }
+                        "ReturnType" | "InstanceType" => type_params
+                            .as_deref()
+                            .and_then(|params| params.params.first())
+                            .and_then(|param| self.resolve_return_type(&ident.sym, param))
+                            .and_then(|ty| self.resolve_indexed_access(&ty, index)),
+                        _ => None,
                     }
                 } else {
                     None
@@ -900,6 +1256,17 @@ where
                     ))
                 }
             }
+            TsType::TsMappedType(..) => {
+                let mut elements = vec![];
+                self.resolve_type_elements(obj, &mut elements);
+                self.resolve_indexed_access(&build_type_lit(elements), index)
+            }
+            TsType::TsConditionalType(conditional) => self
+                .evaluate_conditional_type(conditional)
+                .and_then(|ty| self.resolve_indexed_access(ty, index)),
+            TsType::TsTypeQuery(query) => self
+                .resolve_type_query(query)
+                .and_then(|ty| self.resolve_indexed_access(&ty, index)),
             TsType::TsArrayType(TsArrayType { elem_type, .. }) => {
                 if matches!(
                     index,
@@ -941,61 +1308,53 @@ where
         }
     }
 
-    fn infer_runtime_type(&self, ty: &TsType) -> IndexSet<Option<Atom>> {
+    fn infer_runtime_type(&self, ty: &TsType) -> IndexSet<RuntimeType> {
         let mut runtime_types = IndexSet::with_capacity(1);
         match ty {
             TsType::TsKeywordType(keyword) => match keyword.kind {
                 TsKeywordTypeKind::TsStringKeyword => {
-                    runtime_types.insert(Some(atom!("String")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("String")));
                 }
                 TsKeywordTypeKind::TsNumberKeyword => {
-                    runtime_types.insert(Some(atom!("Number")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Number")));
                 }
                 TsKeywordTypeKind::TsBooleanKeyword => {
-                    runtime_types.insert(Some(atom!("Boolean")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Boolean")));
                 }
                 TsKeywordTypeKind::TsObjectKeyword => {
-                    runtime_types.insert(Some(atom!("Object")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Object")));
                 }
                 TsKeywordTypeKind::TsNullKeyword => {
-                    runtime_types.insert(None);
+                    runtime_types.insert(RuntimeType::Null);
                 }
                 TsKeywordTypeKind::TsBigIntKeyword => {
-                    runtime_types.insert(Some(atom!("BigInt")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("BigInt")));
                 }
                 TsKeywordTypeKind::TsSymbolKeyword => {
-                    runtime_types.insert(Some(atom!("Symbol")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Symbol")));
                 }
                 _ => {
-                    runtime_types.insert(None);
+                    runtime_types.insert(RuntimeType::Unknown);
                 }
             },
             TsType::TsTypeLit(TsTypeLit { members, .. }) => {
-                members.iter().for_each(|member| {
-                    if let TsTypeElement::TsCallSignatureDecl(..)
-                    | TsTypeElement::TsConstructSignatureDecl(..) = member
-                    {
-                        runtime_types.insert(Some(atom!("Function")));
-                    } else {
-                        runtime_types.insert(Some(atom!("Object")));
-                    }
-                });
+                runtime_types.extend(infer_members_runtime_type(members));
             }
             TsType::TsFnOrConstructorType(..) => {
-                runtime_types.insert(Some(atom!("Function")));
+                runtime_types.insert(RuntimeType::Ctor(atom!("Function")));
             }
             TsType::TsArrayType(..) | TsType::TsTupleType(..) => {
-                runtime_types.insert(Some(atom!("Array")));
+                runtime_types.insert(RuntimeType::Ctor(atom!("Array")));
             }
             TsType::TsLitType(TsLitType { lit, .. }) => match lit {
                 TsLit::Str(..) | TsLit::Tpl(..) => {
-                    runtime_types.insert(Some(atom!("String")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("String")));
                 }
                 TsLit::Bool(..) => {
-                    runtime_types.insert(Some(atom!("Boolean")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Boolean")));
                 }
                 TsLit::Number(..) | TsLit::BigInt(..) => {
-                    runtime_types.insert(Some(atom!("Number")));
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Number")));
                 }
             },
             TsType::TsTypeRef(TsTypeRef {
@@ -1004,37 +1363,69 @@ where
                 ..
             }) => {
                 let key = (ident.sym.clone(), ident.ctxt);
-                if let Some(aliased) = self.type_aliases.get(&key) {
-                    runtime_types.extend(self.infer_runtime_type(aliased));
+                if let Some(type_param) = self.type_params.get(&key) {
+                    if let Some(constraint) = type_param {
+                        runtime_types.extend(self.infer_runtime_type(constraint));
+                    } else {
+                        // skip runtime check for unconstrained generic type
+                        runtime_types.insert(RuntimeType::Unknown);
+                    }
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    runtime_types.extend(self.infer_runtime_type(&aliased));
                 } else if let Some(TsInterfaceDecl {
                     body: TsInterfaceBody { body, .. },
                     ..
                 }) = self.interfaces.get(&key)
                 {
-                    body.iter().for_each(|element| {
-                        if let TsTypeElement::TsCallSignatureDecl(..)
-                        | TsTypeElement::TsConstructSignatureDecl(..) = element
-                        {
-                            runtime_types.insert(Some(atom!("Function")));
-                        } else {
-                            runtime_types.insert(Some(atom!("Object")));
-                        }
-                    });
+                    runtime_types.extend(infer_members_runtime_type(body));
+                } else if let Some(TsEnumDecl { members, .. }) = self.enums.get(&key) {
+                    runtime_types.extend(members.iter().map(infer_enum_member_type));
+                } else if let Some(TsType::TsFnOrConstructorType(
+                    TsFnOrConstructorType::TsConstructorType(..),
+                )) = self.value_types.get(&key)
+                {
+                    // class
+                    runtime_types.insert(RuntimeType::Ctor(atom!("Object")));
+                } else if !ident.ctxt.has_mark(self.unresolved_mark)
+                    // synthesized type references like `Promise<T>` don't have syntax context
+                    && ident.ctxt != SyntaxContext::empty()
+                {
+                    // types imported from other modules which can't be resolved
+                    runtime_types.insert(RuntimeType::Unknown);
                 } else {
                     match &*ident.sym {
                         "Array" | "Function" | "Object" | "Set" | "Map" | "WeakSet" | "WeakMap"
                         | "Date" | "Promise" | "Error" | "RegExp" => {
-                            runtime_types.insert(Some(ident.sym.clone()));
+                            runtime_types.insert(RuntimeType::Ctor(ident.sym.clone()));
+                        }
+                        "Partial" | "Required" | "Readonly" | "Record" | "Pick" | "Omit" => {
+                            runtime_types.insert(RuntimeType::Ctor(atom!("Object")));
                         }
-                        "Partial" | "Required" | "Readonly" | "Record" | "Pick" | "Omit"
-                        | "InstanceType" => {
-                            runtime_types.insert(Some(atom!("Object")));
+                        "ReturnType" | "InstanceType" => {
+                            if let Some(ty) = type_params
+                                .as_deref()
+                                .and_then(|params| params.params.first())
+                                .and_then(|param| self.resolve_return_type(&ident.sym, param))
+                            {
+                                runtime_types.extend(self.infer_runtime_type(&ty));
+                            } else if ident.sym == "InstanceType" {
+                                runtime_types.insert(RuntimeType::Ctor(atom!("Object")));
+                            } else {
+                                runtime_types.insert(RuntimeType::Unknown);
+                            }
                         }
                         "Uppercase" | "Lowercase" | "Capitalize" | "Uncapitalize" => {
-                            runtime_types.insert(Some(atom!("String")));
+                            runtime_types.insert(RuntimeType::Ctor(atom!("String")));
                         }
-                        "Parameters" | "ConstructorParameters" => {
-                            runtime_types.insert(Some(atom!("Array")));
+                        "Parameters" | "ConstructorParameters" | "ReadonlyArray" => {
+                            runtime_types.insert(RuntimeType::Ctor(atom!("Array")));
+                        }
+                        "ReadonlyMap" => {
+                            runtime_types.insert(RuntimeType::Ctor(atom!("Map")));
+                        }
+                        "ReadonlySet" => {
+                            runtime_types.insert(RuntimeType::Ctor(atom!("Set")));
                         }
                         "NonNullable" => {
                             if let Some(ty) = type_params
@@ -1042,9 +1433,11 @@ where
                                 .and_then(|type_params| type_params.params.first())
                             {
                                 let types = self.infer_runtime_type(ty);
-                                runtime_types.extend(types.into_iter().filter(|ty| ty.is_some()));
+                                runtime_types.extend(
+                                    types.into_iter().filter(|ty| *ty != RuntimeType::Null),
+                                );
                             } else {
-                                runtime_types.insert(Some(atom!("Object")));
+                                runtime_types.insert(RuntimeType::Unknown);
                             }
                         }
                         "Exclude" | "OmitThisParameter" => {
@@ -1054,7 +1447,7 @@ where
                             {
                                 runtime_types.extend(self.infer_runtime_type(ty));
                             } else {
-                                runtime_types.insert(Some(atom!("Object")));
+                                runtime_types.insert(RuntimeType::Unknown);
                             }
                         }
                         "Extract" => {
@@ -1064,22 +1457,69 @@ where
                             {
                                 runtime_types.extend(self.infer_runtime_type(ty));
                             } else {
-                                runtime_types.insert(Some(atom!("Object")));
+                                runtime_types.insert(RuntimeType::Unknown);
                             }
                         }
                         _ => {
-                            runtime_types.insert(Some(atom!("Object")));
+                            runtime_types.insert(RuntimeType::Unknown);
                         }
                     }
                 }
             }
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::TsQualifiedName(qualified_name),
+                ..
+            }) => {
+                if let Some(member) = self.resolve_enum_member(qualified_name) {
+                    runtime_types.insert(infer_enum_member_type(member));
+                } else {
+                    runtime_types.insert(RuntimeType::Unknown);
+                }
+            }
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::KeyOf,
+                ..
+            }) => {
+                runtime_types.insert(RuntimeType::Ctor(atom!("String")));
+            }
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::ReadOnly,
+                type_ann,
+                ..
+            }) => {
+                runtime_types.extend(self.infer_runtime_type(type_ann));
+            }
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::Unique,
+                ..
+            }) => {
+                runtime_types.insert(RuntimeType::Ctor(atom!("Symbol")));
+            }
+            TsType::TsConditionalType(conditional) => {
+                if let Some(ty) = self.evaluate_conditional_type(conditional) {
+                    runtime_types.extend(self.infer_runtime_type(ty));
+                } else {
+                    runtime_types.extend(self.infer_runtime_type(&conditional.true_type));
+                    runtime_types.extend(self.infer_runtime_type(&conditional.false_type));
+                }
+            }
             TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
                 runtime_types.extend(self.infer_runtime_type(type_ann));
             }
-            TsType::TsUnionOrIntersectionType(
-                TsUnionOrIntersectionType::TsUnionType(TsUnionType { types, .. })
-                | TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType { types, .. }),
-            ) => runtime_types.extend(types.iter().flat_map(|ty| self.infer_runtime_type(ty))),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                TsUnionType { types, .. },
+            )) => runtime_types.extend(types.iter().flat_map(|ty| self.infer_runtime_type(ty))),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                TsIntersectionType { types, .. },
+            )) => {
+                // intersection containing unknown type will be narrowed to the known types
+                runtime_types.extend(
+                    types
+                        .iter()
+                        .flat_map(|ty| self.infer_runtime_type(ty))
+                        .filter(|ty| *ty != RuntimeType::Unknown),
+                );
+            }
             TsType::TsIndexedAccessType(TsIndexedAccessType {
                 obj_type,
                 index_type,
@@ -1087,98 +1527,1468 @@ where
             }) => {
                 if let Some(ty) = self.resolve_indexed_access(obj_type, index_type) {
                     runtime_types.extend(self.infer_runtime_type(&ty));
+                } else {
+                    runtime_types.insert(RuntimeType::Unknown);
                 }
             }
+            TsType::TsMappedType(..) => {
+                runtime_types.insert(RuntimeType::Ctor(atom!("Object")));
+            }
             TsType::TsOptionalType(TsOptionalType { type_ann, .. }) => {
                 runtime_types.extend(self.infer_runtime_type(type_ann));
             }
+            TsType::TsTypeQuery(query) => {
+                if let Some(ty) = self.resolve_type_query(query) {
+                    runtime_types.extend(self.infer_runtime_type(&ty));
+                } else {
+                    runtime_types.insert(RuntimeType::Unknown);
+                }
+            }
             _ => {
-                runtime_types.insert(Some(atom!("Object")));
+                runtime_types.insert(RuntimeType::Unknown);
             }
         };
         runtime_types
     }
 
-    pub(crate) fn extract_emits_type(&self, setup_fn: &ExprOrSpread) -> Option<ArrayLit> {
-        let TsTypeAnn {
-            type_ann: second_param_type,
-            ..
-        } = if let ExprOrSpread { expr, spread: None } = setup_fn {
-            match &**expr {
-                Expr::Arrow(arrow) => match arrow.params.get(1) {
-                    Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
-                    Some(Pat::Array(array)) => array.type_ann.as_deref(),
-                    Some(Pat::Object(object)) => object.type_ann.as_deref(),
-                    _ => return None,
-                },
-                Expr::Fn(fn_expr) => match fn_expr.function.params.get(1).map(|param| &param.pat) {
-                    Some(Pat::Ident(ident)) => ident.type_ann.as_deref(),
-                    Some(Pat::Array(array)) => array.type_ann.as_deref(),
-                    Some(Pat::Object(object)) => object.type_ann.as_deref(),
-                    _ => return None,
-                },
-                _ => return None,
-            }?
-        } else {
+    /// Resolve type query like `typeof foo` or `typeof foo.bar` from types of local bindings.
+    fn resolve_type_query(&self, query: &TsTypeQuery) -> Option<TsType> {
+        let TsTypeQueryExpr::TsEntityName(entity_name) = &query.expr_name else {
             return None;
         };
+        self.resolve_entity_value_type(entity_name)
+    }
+
+    fn resolve_entity_value_type(&self, entity_name: &TsEntityName) -> Option<TsType> {
+        match entity_name {
+            TsEntityName::Ident(ident) => self
+                .value_types
+                .get(&(ident.sym.clone(), ident.ctxt))
+                .cloned(),
+            TsEntityName::TsQualifiedName(qualified_name) => {
+                let obj = self.resolve_entity_value_type(&qualified_name.left)?;
+                self.resolve_indexed_access(
+                    &obj,
+                    &TsType::TsLitType(TsLitType {
+                        lit: TsLit::Str(quote_str!(qualified_name.right.sym.clone())),
+                        span: DUMMY_SP,
+                    }),
+                )
+            }
+        }
+    }
 
-        match &**second_param_type {
+    /// Evaluate `ReturnType<T>` or `InstanceType<T>`.
+    fn resolve_return_type(&self, utility: &str, ty: &TsType) -> Option<TsType> {
+        match ty {
+            TsType::TsTypeQuery(query) => self
+                .resolve_type_query(query)
+                .and_then(|ty| self.resolve_return_type(utility, &ty)),
             TsType::TsTypeRef(TsTypeRef {
                 type_name: TsEntityName::Ident(ident),
-                type_params: Some(type_params),
+                type_params,
                 ..
-            }) if ident.sym == "SetupContext" => {
-                if let Some(emits_def) = type_params.params.first() {
-                    let mut emits = Vec::with_capacity(1);
-                    self.resolve_type_elements(emits_def, &mut emits);
-                    Some(ArrayLit {
-                        elems: emits
-                            .into_iter()
-                            .flat_map(|emit| match emit {
-                                RefinedTsTypeElement::MethodSignature(TsMethodSignature {
-                                    key,
-                                    ..
-                                })
-                                | RefinedTsTypeElement::Property(TsPropertySignature {
-                                    key, ..
-                                }) => match &*key {
-                                    Expr::Ident(ident) => vec![ident.sym.clone()],
-                                    Expr::Lit(Lit::Str(str)) => vec![str.value.clone()],
-                                    _ => vec![],
-                                },
-                                RefinedTsTypeElement::CallSignature(TsCallSignatureDecl {
-                                    params,
-                                    ..
-                                }) => params
-                                    .first()
-                                    .and_then(|param| match param {
-                                        TsFnParam::Ident(ident) => ident.type_ann.as_deref(),
-                                        TsFnParam::Array(array) => array.type_ann.as_deref(),
-                                        TsFnParam::Rest(rest) => rest.type_ann.as_deref(),
-                                        TsFnParam::Object(object) => object.type_ann.as_deref(),
-                                    })
-                                    .map(|type_ann| {
-                                        self.resolve_string_or_union_strings(&type_ann.type_ann)
-                                    })
-                                    .unwrap_or_default(),
-                                RefinedTsTypeElement::GetterSignature(..) => vec![],
-                            })
-                            .map(|name| {
-                                Some(ExprOrSpread {
-                                    expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
-                                    spread: None,
-                                })
-                            })
-                            .collect(),
+            }) => self
+                .resolve_type_alias(&(ident.sym.clone(), ident.ctxt), type_params.as_deref())
+                .and_then(|aliased| self.resolve_return_type(utility, &aliased)),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.resolve_return_type(utility, type_ann)
+            }
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+                type_ann,
+                ..
+            })) if utility == "ReturnType" => Some((*type_ann.type_ann).clone()),
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(
+                TsConstructorType { type_ann, .. },
+            )) if utility == "InstanceType" => Some((*type_ann.type_ann).clone()),
+            _ => None,
+        }
+    }
+
+    /// Infer type of expression, which is used for resolving `typeof` type queries.
+    pub(crate) fn infer_type_from_expr(&self, expr: &Expr) -> Option<TsType> {
+        match expr {
+            Expr::Lit(lit) => match lit {
+                Lit::Str(..) => Some(build_keyword_type(TsKeywordTypeKind::TsStringKeyword)),
+                Lit::Num(..) => Some(build_keyword_type(TsKeywordTypeKind::TsNumberKeyword)),
+                Lit::Bool(..) => Some(build_keyword_type(TsKeywordTypeKind::TsBooleanKeyword)),
+                Lit::BigInt(..) => Some(build_keyword_type(TsKeywordTypeKind::TsBigIntKeyword)),
+                Lit::Null(..) => Some(build_keyword_type(TsKeywordTypeKind::TsNullKeyword)),
+                Lit::Regex(..) => Some(build_global_type_ref("RegExp")),
+                Lit::JSXText(..) => None,
+            },
+            Expr::Tpl(..) => Some(build_keyword_type(TsKeywordTypeKind::TsStringKeyword)),
+            Expr::Array(ArrayLit { elems, .. }) => Some(TsType::TsArrayType(TsArrayType {
+                elem_type: Box::new(
+                    elems
+                        .iter()
+                        .flatten()
+                        .find_map(|elem| {
+                            if elem.spread.is_none() {
+                                self.infer_type_from_expr(&elem.expr)
+                            } else {
+                                None
+                            }
+                        })
+                        .unwrap_or_else(|| build_keyword_type(TsKeywordTypeKind::TsUnknownKeyword)),
+                ),
+                span: DUMMY_SP,
+            })),
+            Expr::Object(ObjectLit { props, .. }) => {
+                let mut members = vec![];
+                props.iter().for_each(|prop| match prop {
+                    PropOrSpread::Prop(prop) => match &**prop {
+                        Prop::Shorthand(ident) => {
+                            members.push(TsTypeElement::TsPropertySignature(
+                                build_property_signature(
+                                    ident.sym.clone(),
+                                    self.value_types
+                                        .get(&(ident.sym.clone(), ident.ctxt))
+                                        .cloned(),
+                                    false,
+                                ),
+                            ));
+                        }
+                        Prop::KeyValue(KeyValueProp { key, value }) => {
+                            if let Some(key) = prop_name_to_atom(key) {
+                                members.push(TsTypeElement::TsPropertySignature(
+                                    build_property_signature(
+                                        key,
+                                        self.infer_type_from_expr(value),
+                                        false,
+                                    ),
+                                ));
+                            }
+                        }
+                        Prop::Getter(GetterProp {
+                            key,
+                            type_ann,
+                            body,
+                            ..
+                        }) => {
+                            if let Some(key) = prop_name_to_atom(key) {
+                                let ty = type_ann
+                                    .as_ref()
+                                    .map(|type_ann| (*type_ann.type_ann).clone())
+                                    .or_else(|| {
+                                        body.as_ref()
+                                            .and_then(|body| self.infer_type_from_block(body))
+                                    });
+                                members.push(TsTypeElement::TsPropertySignature(
+                                    build_property_signature(key, ty, false),
+                                ));
+                            }
+                        }
+                        Prop::Method(MethodProp { key, function }) => {
+                            if let Some(key) = prop_name_to_atom(key) {
+                                members.push(TsTypeElement::TsPropertySignature(
+                                    build_property_signature(
+                                        key,
+                                        Some(self.infer_type_from_function(function)),
+                                        false,
+                                    ),
+                                ));
+                            }
+                        }
+                        Prop::Assign(..) | Prop::Setter(..) => {}
+                    },
+                    PropOrSpread::Spread(SpreadElement { expr, .. }) => {
+                        if let Some(TsType::TsTypeLit(TsTypeLit {
+                            members: spread, ..
+                        })) = self.infer_type_from_expr(expr)
+                        {
+                            members.extend(spread);
+                        }
+                    }
+                });
+                Some(TsType::TsTypeLit(TsTypeLit {
+                    members,
+                    span: DUMMY_SP,
+                }))
+            }
+            Expr::Fn(FnExpr { function, .. }) => Some(self.infer_type_from_function(function)),
+            Expr::Arrow(arrow) => Some(self.infer_type_from_arrow(arrow)),
+            Expr::Class(ClassExpr { class, .. }) => Some(self.infer_type_from_class(class)),
+            Expr::Ident(ident) => self
+                .value_types
+                .get(&(ident.sym.clone(), ident.ctxt))
+                .cloned()
+                .or_else(|| {
+                    (ident.sym == "undefined" && ident.ctxt.has_mark(self.unresolved_mark))
+                        .then(|| build_keyword_type(TsKeywordTypeKind::TsUndefinedKeyword))
+                }),
+            Expr::New(NewExpr { callee, .. }) => match &**callee {
+                Expr::Ident(ident) => {
+                    match self.value_types.get(&(ident.sym.clone(), ident.ctxt)) {
+                        Some(TsType::TsFnOrConstructorType(
+                            TsFnOrConstructorType::TsConstructorType(TsConstructorType {
+                                type_ann,
+                                ..
+                            }),
+                        )) => Some((*type_ann.type_ann).clone()),
+                        Some(..) => None,
+                        None => Some(TsType::TsTypeRef(TsTypeRef {
+                            type_name: TsEntityName::Ident(ident.clone()),
+                            type_params: None,
+                            span: DUMMY_SP,
+                        })),
+                    }
+                }
+                _ => None,
+            },
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match self.infer_type_from_expr(callee)? {
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+                    type_ann,
+                    ..
+                })) => Some(*type_ann.type_ann),
+                _ => None,
+            },
+            Expr::Unary(UnaryExpr { op, .. }) => match op {
+                UnaryOp::Bang | UnaryOp::Delete => {
+                    Some(build_keyword_type(TsKeywordTypeKind::TsBooleanKeyword))
+                }
+                UnaryOp::Minus | UnaryOp::Plus | UnaryOp::Tilde => {
+                    Some(build_keyword_type(TsKeywordTypeKind::TsNumberKeyword))
+                }
+                UnaryOp::TypeOf => Some(build_keyword_type(TsKeywordTypeKind::TsStringKeyword)),
+                UnaryOp::Void => Some(build_keyword_type(TsKeywordTypeKind::TsUndefinedKeyword)),
+            },
+            Expr::TsAs(TsAsExpr { type_ann, .. })
+            | Expr::TsTypeAssertion(TsTypeAssertion { type_ann, .. }) => Some((**type_ann).clone()),
+            Expr::Paren(ParenExpr { expr, .. })
+            | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+            | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+            | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => self.infer_type_from_expr(expr),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn infer_type_from_function(&self, function: &Function) -> TsType {
+        build_fn_type(
+            function.params.iter().map(|param| &param.pat),
+            self.infer_return_type(function),
+        )
+    }
+
+    fn infer_return_type(&self, function: &Function) -> Option<TsType> {
+        function
+            .return_type
+            .as_ref()
+            .map(|type_ann| (*type_ann.type_ann).clone())
+            .or_else(|| {
+                let ty = function
+                    .body
+                    .as_ref()
+                    .and_then(|body| self.infer_type_from_block(body))?;
+                Some(if function.is_async {
+                    build_promise_type(ty)
+                } else {
+                    ty
+                })
+            })
+    }
+
+    fn infer_type_from_arrow(&self, arrow: &ArrowExpr) -> TsType {
+        let return_type = arrow
+            .return_type
+            .as_ref()
+            .map(|type_ann| (*type_ann.type_ann).clone())
+            .or_else(|| {
+                let ty = match &*arrow.body {
+                    BlockStmtOrExpr::BlockStmt(block) => self.infer_type_from_block(block),
+                    BlockStmtOrExpr::Expr(expr) => self.infer_type_from_expr(expr),
+                }?;
+                Some(if arrow.is_async {
+                    build_promise_type(ty)
+                } else {
+                    ty
+                })
+            });
+        build_fn_type(arrow.params.iter(), return_type)
+    }
+
+    /// Infer return type of function body from its first top-level `return` statement.
+    fn infer_type_from_block(&self, block: &BlockStmt) -> Option<TsType> {
+        block.stmts.iter().find_map(|stmt| match stmt {
+            Stmt::Return(ReturnStmt { arg, .. }) => Some(
+                arg.as_ref()
+                    .and_then(|arg| self.infer_type_from_expr(arg))
+                    .unwrap_or_else(|| build_keyword_type(TsKeywordTypeKind::TsUnknownKeyword)),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Infer type of class as constructor type whose instance type consists of
+    /// non-static properties, methods and getters.
+    pub(crate) fn infer_type_from_class(&self, class: &Class) -> TsType {
+        let members = class
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::ClassProp(ClassProp {
+                    key,
+                    value,
+                    type_ann,
+                    is_static: false,
+                    is_optional,
+                    ..
+                }) => Some(build_property_signature(
+                    prop_name_to_atom(key)?,
+                    type_ann
+                        .as_ref()
+                        .map(|type_ann| (*type_ann.type_ann).clone())
+                        .or_else(|| {
+                            value
+                                .as_ref()
+                                .and_then(|value| self.infer_type_from_expr(value))
+                        }),
+                    *is_optional,
+                )),
+                ClassMember::Method(ClassMethod {
+                    key,
+                    function,
+                    kind,
+                    is_static: false,
+                    is_optional,
+                    ..
+                }) => {
+                    let ty = match kind {
+                        MethodKind::Method => Some(self.infer_type_from_function(function)),
+                        MethodKind::Getter => self.infer_return_type(function),
+                        MethodKind::Setter => return None,
+                    };
+                    Some(build_property_signature(
+                        prop_name_to_atom(key)?,
+                        ty,
+                        *is_optional,
+                    ))
+                }
+                _ => None,
+            })
+            .map(TsTypeElement::TsPropertySignature)
+            .collect();
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(
+            TsConstructorType {
+                params: vec![],
+                type_params: None,
+                type_ann: Box::new(TsTypeAnn {
+                    type_ann: Box::new(TsType::TsTypeLit(TsTypeLit {
+                        members,
                         span: DUMMY_SP,
+                    })),
+                    span: DUMMY_SP,
+                }),
+                is_abstract: class.is_abstract,
+                span: DUMMY_SP,
+            },
+        ))
+    }
+
+    /// Look up type alias by key. If it's a generic type alias,
+    /// type parameters will be replaced with the given type arguments.
+    ///
+    /// Recursive type alias which references itself with the same type arguments
+    /// or is nested too deeply is resolved as `unknown`, so no runtime check will be generated.
+    fn resolve_type_alias(
+        &self,
+        key: &(Atom, SyntaxContext),
+        type_args: Option<&TsTypeParamInstantiation>,
+    ) -> Option<ResolvedTypeAlias<'_>> {
+        let aliased = self.type_aliases.get(key)?;
+        {
+            let mut resolving = self.resolving_type_aliases.borrow_mut();
+            if resolving.len() >= MAX_TYPE_ALIAS_DEPTH
+                || resolving.iter().any(|(resolving_key, resolving_args)| {
+                    resolving_key == key
+                        && match (resolving_args, type_args) {
+                            (Some(a), Some(b)) => a.eq_ignore_span(b),
+                            (None, None) => true,
+                            _ => false,
+                        }
+                })
+            {
+                return Some(ResolvedTypeAlias {
+                    ty: Cow::Owned(TsType::TsKeywordType(TsKeywordType {
+                        kind: TsKeywordTypeKind::TsUnknownKeyword,
+                        span: aliased.span(),
+                    })),
+                    resolving: None,
+                });
+            }
+            resolving.push((key.clone(), type_args.cloned()));
+        }
+        let ty = self.substitute_type_alias(key, aliased, type_args);
+        Some(ResolvedTypeAlias {
+            ty,
+            resolving: Some(&self.resolving_type_aliases),
+        })
+    }
+
+    fn substitute_type_alias<'a>(
+        &self,
+        key: &(Atom, SyntaxContext),
+        aliased: &'a TsType,
+        type_args: Option<&TsTypeParamInstantiation>,
+    ) -> Cow<'a, TsType> {
+        if let Some(TsTypeParamDecl { params, .. }) = self.type_alias_params.get(key) {
+            let replacements = params
+                .iter()
+                .enumerate()
+                .map(|(i, param)| {
+                    let ty = type_args
+                        .and_then(|args| args.params.get(i))
+                        .or(param.default.as_ref())
+                        .map(|ty| (**ty).clone())
+                        .unwrap_or(TsType::TsKeywordType(TsKeywordType {
+                            kind: TsKeywordTypeKind::TsUnknownKeyword,
+                            span: DUMMY_SP,
+                        }));
+                    ((param.name.sym.clone(), param.name.ctxt), ty)
+                })
+                .collect();
+            Cow::Owned(substitute_type_params(aliased, &replacements))
+        } else {
+            Cow::Borrowed(aliased)
+        }
+    }
+
+    fn resolve_mapped_type(
+        &self,
+        TsMappedType {
+            type_param,
+            name_type,
+            optional,
+            type_ann,
+            span,
+            ..
+        }: &TsMappedType,
+        props: &mut Vec<RefinedTsTypeElement>,
+    ) {
+        if name_type.is_some() {
+            HANDLER.with(|handler| {
+                handler.span_err(*span, "Key remapping in mapped types isn't supported.");
+            });
+            return;
+        }
+        let Some(constraint) = &type_param.constraint else {
+            return;
+        };
+
+        // homomorphic mapped types like `{ [K in keyof T]: T[K] }` preserve optional modifiers
+        let mut original_optional = FnvHashMap::default();
+        if let TsType::TsTypeOperator(TsTypeOperator {
+            op: TsTypeOperatorOp::KeyOf,
+            type_ann,
+            ..
+        }) = &**constraint
+        {
+            let mut elements = vec![];
+            self.resolve_type_elements(type_ann, &mut elements);
+            original_optional.extend(elements.iter().filter_map(|element| {
+                let optional = match element {
+                    RefinedTsTypeElement::Property(TsPropertySignature { optional, .. })
+                    | RefinedTsTypeElement::MethodSignature(TsMethodSignature {
+                        optional, ..
+                    }) => *optional,
+                    _ => false,
+                };
+                element.key().map(|key| (key.clone(), optional))
+            }));
+        }
+
+        let param = (type_param.name.sym.clone(), type_param.name.ctxt);
+        props.extend(
+            self.resolve_string_or_union_strings(constraint)
+                .into_iter()
+                .map(|key| {
+                    let mut replacements = FnvHashMap::default();
+                    replacements.insert(
+                        param.clone(),
+                        TsType::TsLitType(TsLitType {
+                            lit: TsLit::Str(quote_str!(key.clone())),
+                            span: DUMMY_SP,
+                        }),
+                    );
+                    let optional = match optional {
+                        Some(TruePlusMinus::True | TruePlusMinus::Plus) => true,
+                        Some(TruePlusMinus::Minus) => false,
+                        None => original_optional.get(&key).copied().unwrap_or_default(),
+                    };
+                    RefinedTsTypeElement::Property(build_property_signature(
+                        key,
+                        type_ann
+                            .as_deref()
+                            .map(|ty| substitute_type_params(ty, &replacements)),
+                        optional,
+                    ))
+                }),
+        );
+    }
+
+    /// Evaluate simple conditional type like `T extends string ? A : B`.
+    /// It returns `None` if it can't be determined.
+    fn evaluate_conditional_type<'a>(
+        &self,
+        conditional: &'a TsConditionalType,
+    ) -> Option<&'a TsType> {
+        self.is_assignable(&conditional.check_type, &conditional.extends_type)
+            .map(|assignable| {
+                if assignable {
+                    &*conditional.true_type
+                } else {
+                    &*conditional.false_type
+                }
+            })
+    }
+
+    fn is_assignable(&self, source: &TsType, target: &TsType) -> Option<bool> {
+        match (source, target) {
+            (
+                _,
+                TsType::TsKeywordType(TsKeywordType {
+                    kind: TsKeywordTypeKind::TsAnyKeyword | TsKeywordTypeKind::TsUnknownKeyword,
+                    ..
+                }),
+            ) => Some(true),
+            (TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }), _) => {
+                self.is_assignable(type_ann, target)
+            }
+            (_, TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. })) => {
+                self.is_assignable(source, type_ann)
+            }
+            (
+                TsType::TsTypeRef(TsTypeRef {
+                    type_name: TsEntityName::Ident(ident),
+                    type_params,
+                    ..
+                }),
+                _,
+            ) if !self
+                .type_params
+                .contains_key(&(ident.sym.clone(), ident.ctxt)) =>
+            {
+                self.resolve_type_alias(&(ident.sym.clone(), ident.ctxt), type_params.as_deref())
+                    .and_then(|aliased| self.is_assignable(&aliased, target))
+            }
+            (
+                _,
+                TsType::TsTypeRef(TsTypeRef {
+                    type_name: TsEntityName::Ident(ident),
+                    type_params,
+                    ..
+                }),
+            ) if !self
+                .type_params
+                .contains_key(&(ident.sym.clone(), ident.ctxt)) =>
+            {
+                self.resolve_type_alias(&(ident.sym.clone(), ident.ctxt), type_params.as_deref())
+                    .and_then(|aliased| self.is_assignable(source, &aliased))
+            }
+            (
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                    TsUnionType { types, .. },
+                )),
+                _,
+            ) => {
+                let results = types
+                    .iter()
+                    .map(|ty| self.is_assignable(ty, target))
+                    .collect::<Option<Vec<_>>>()?;
+                if results.iter().all(|assignable| *assignable) {
+                    Some(true)
+                } else if results.iter().all(|assignable| !assignable) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            (
+                _,
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                    TsUnionType { types, .. },
+                )),
+            ) => {
+                let results = types
+                    .iter()
+                    .map(|ty| self.is_assignable(source, ty))
+                    .collect::<Vec<_>>();
+                if results.contains(&Some(true)) {
+                    Some(true)
+                } else if results.iter().all(|assignable| *assignable == Some(false)) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            (TsType::TsKeywordType(source), TsType::TsKeywordType(target)) => {
+                if source.kind == target.kind {
+                    Some(true)
+                } else if is_primitive_keyword(source.kind) && is_primitive_keyword(target.kind) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            (TsType::TsLitType(TsLitType { lit, .. }), TsType::TsKeywordType(target)) => {
+                let kind = match lit {
+                    TsLit::Str(..) | TsLit::Tpl(..) => TsKeywordTypeKind::TsStringKeyword,
+                    TsLit::Number(..) => TsKeywordTypeKind::TsNumberKeyword,
+                    TsLit::Bool(..) => TsKeywordTypeKind::TsBooleanKeyword,
+                    TsLit::BigInt(..) => TsKeywordTypeKind::TsBigIntKeyword,
+                };
+                if kind == target.kind {
+                    Some(true)
+                } else if is_primitive_keyword(target.kind) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            (TsType::TsLitType(source), TsType::TsLitType(target)) => {
+                Some(source.lit.eq_ignore_span(&target.lit))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve enum member type like `Size.Small`.
+    fn resolve_enum_member(&self, qualified_name: &TsQualifiedName) -> Option<&TsEnumMember> {
+        let TsEntityName::Ident(ident) = &qualified_name.left else {
+            return None;
+        };
+        self.enums
+            .get(&(ident.sym.clone(), ident.ctxt))?
+            .members
+            .iter()
+            .find(|member| *member.id.as_ref() == qualified_name.right.sym)
+    }
+
+    /// Resolve enum from type query like `typeof Size`.
+    fn resolve_enum_from_type_query(&self, ty: &TsType) -> Option<&TsEnumDecl> {
+        if let TsType::TsTypeQuery(TsTypeQuery {
+            expr_name: TsTypeQueryExpr::TsEntityName(TsEntityName::Ident(ident)),
+            ..
+        }) = ty
+        {
+            self.enums.get(&(ident.sym.clone(), ident.ctxt))
+        } else {
+            None
+        }
+    }
+
+    /// Extract emits option from type of setup context, along with emit names.
+    pub(crate) fn extract_emits_type(
+        &self,
+        setup_fn: &ExprOrSpread,
+    ) -> Option<(Expr, Vec<String>)> {
+        let second_param_type = get_setup_context_param(setup_fn).and_then(get_pat_type)?;
+        let emits_def = self.find_emits_def(second_param_type)?;
+        let mut emits = Vec::with_capacity(1);
+        self.resolve_type_elements(&emits_def, &mut emits);
+        let emits = emits.into_iter().flat_map(|emit| match emit {
+            RefinedTsTypeElement::MethodSignature(TsMethodSignature { key, params, .. }) => {
+                match &*key {
+                    Expr::Ident(ident) => {
+                        vec![(ident.sym.clone(), fn_params_to_payload(&params))]
+                    }
+                    Expr::Lit(Lit::Str(str)) => {
+                        vec![(str.value.clone(), fn_params_to_payload(&params))]
+                    }
+                    _ => vec![],
+                }
+            }
+            RefinedTsTypeElement::Property(TsPropertySignature { key, type_ann, .. }) => {
+                let payload = type_ann
+                    .map(|type_ann| self.resolve_emit_payload(&type_ann.type_ann))
+                    .unwrap_or_default();
+                match &*key {
+                    Expr::Ident(ident) => vec![(ident.sym.clone(), payload)],
+                    Expr::Lit(Lit::Str(str)) => vec![(str.value.clone(), payload)],
+                    _ => vec![],
+                }
+            }
+            RefinedTsTypeElement::CallSignature(TsCallSignatureDecl { params, .. }) => {
+                let payload = fn_params_to_payload(params.get(1..).unwrap_or_default());
+                params
+                    .first()
+                    .and_then(|param| match param {
+                        TsFnParam::Ident(ident) => ident.type_ann.as_deref(),
+                        TsFnParam::Array(array) => array.type_ann.as_deref(),
+                        TsFnParam::Rest(rest) => rest.type_ann.as_deref(),
+                        TsFnParam::Object(object) => object.type_ann.as_deref(),
+                    })
+                    .map(|type_ann| self.resolve_string_or_union_strings(&type_ann.type_ann))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|name| (name, payload.clone()))
+                    .collect()
+            }
+            RefinedTsTypeElement::GetterSignature(..) => vec![],
+        });
+        // the same event can be declared by multiple overloads with different payloads
+        let emits = emits.fold(
+            IndexMap::<Atom, Vec<Vec<EmitPayloadParam>>>::new(),
+            |mut emits, (name, payload)| {
+                emits.entry(name).or_default().push(payload);
+                emits
+            },
+        );
+        let names = emits.keys().map(|name| name.to_string()).collect();
+
+        let emits = emits.into_iter();
+        let option = if self.options.emits_validators {
+            Expr::Object(ObjectLit {
+                props: emits
+                    .map(|(name, overloads)| {
+                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: if Ident::verify_symbol(&name).is_ok() {
+                                PropName::Ident(quote_ident!(name))
+                            } else {
+                                PropName::Str(quote_str!(name))
+                            },
+                            value: Box::new(self.build_emit_validator(overloads)),
+                        })))
+                    })
+                    .collect(),
+                span: DUMMY_SP,
+            })
+        } else {
+            Expr::Array(ArrayLit {
+                elems: emits
+                    .map(|(name, _)| {
+                        Some(ExprOrSpread {
+                            expr: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
+                            spread: None,
+                        })
                     })
+                    .collect(),
+                span: DUMMY_SP,
+            })
+        };
+        Some((option, names))
+    }
+
+    /// Find emits definition from type of setup context, which can be `SetupContext<E>`,
+    /// `Pick<SetupContext<E>, 'emit'>` or object type with `emit` member like `{ emit: (e: 'change') => void }`.
+    fn find_emits_def(&self, ty: &TsType) -> Option<TsType> {
+        match ty {
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ident),
+                type_params,
+                ..
+            }) => {
+                let key = (ident.sym.clone(), ident.ctxt);
+                if ident.sym == "SetupContext" {
+                    type_params
+                        .as_deref()
+                        .and_then(|params| params.params.first())
+                        .map(|ty| (**ty).clone())
+                } else if let Some(aliased) = self.resolve_type_alias(&key, type_params.as_deref())
+                {
+                    self.find_emits_def(&aliased)
+                } else if self.interfaces.contains_key(&key) {
+                    self.find_emit_member(ty)
+                } else if ident.ctxt.has_mark(self.unresolved_mark)
+                    && matches!(
+                        &*ident.sym,
+                        "Pick" | "Omit" | "Partial" | "Required" | "Readonly"
+                    )
+                {
+                    let params = &type_params.as_deref()?.params;
+                    // `emit` must be kept by the key set of `Pick` and `Omit`
+                    if let Some(keys) = params
+                        .get(1)
+                        .filter(|_| ident.sym == "Pick" || ident.sym == "Omit")
+                    {
+                        let picked = self
+                            .resolve_string_or_union_strings(keys)
+                            .contains(&atom!("emit"));
+                        if picked != (ident.sym == "Pick") {
+                            return None;
+                        }
+                    }
+                    params.first().and_then(|ty| self.find_emits_def(ty))
                 } else {
                     None
                 }
             }
+            TsType::TsTypeLit(..) => self.find_emit_member(ty),
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                TsIntersectionType { types, .. },
+            )) => types.iter().find_map(|ty| self.find_emits_def(ty)),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.find_emits_def(type_ann)
+            }
+            _ => None,
+        }
+    }
+
+    /// Find type of `emit` member in object type, which can be used as emits definition.
+    fn find_emit_member(&self, ty: &TsType) -> Option<TsType> {
+        let mut elements = vec![];
+        self.resolve_type_elements(ty, &mut elements);
+        elements
+            .into_iter()
+            .find(|element| element.key().is_some_and(|key| key == "emit"))
+            .and_then(|element| match element {
+                RefinedTsTypeElement::Property(TsPropertySignature { type_ann, .. }) => {
+                    type_ann.map(|type_ann| *type_ann.type_ann)
+                }
+                RefinedTsTypeElement::MethodSignature(TsMethodSignature {
+                    params,
+                    type_ann,
+                    type_params,
+                    ..
+                }) => Some(TsType::TsFnOrConstructorType(
+                    TsFnOrConstructorType::TsFnType(TsFnType {
+                        params,
+                        type_params,
+                        type_ann: type_ann.unwrap_or_else(|| {
+                            Box::new(TsTypeAnn {
+                                type_ann: Box::new(build_keyword_type(
+                                    TsKeywordTypeKind::TsVoidKeyword,
+                                )),
+                                span: DUMMY_SP,
+                            })
+                        }),
+                        span: DUMMY_SP,
+                    }),
+                )),
+                _ => None,
+            })
+    }
+
+    /// Generate runtime marker of slots if slots type is specified by `SetupContext<E, S>`.
+    /// Slots type can't be checked at runtime, so it's always `Object` like `Object as SlotsType<S>`.
+    /// Slot names are also returned if they can be resolved.
+    pub(crate) fn extract_slots_type(
+        &self,
+        setup_fn: &ExprOrSpread,
+    ) -> Option<(Expr, Vec<String>)> {
+        match get_setup_context_param(setup_fn).and_then(get_pat_type)? {
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ident),
+                type_params: Some(type_params),
+                ..
+            }) if ident.sym == "SetupContext" && type_params.params.len() > 1 => {
+                let slots_type = &*type_params.params[1];
+                // slots type is only used as metadata, so unresolvable types shouldn't be reported
+                let resolvable = match slots_type {
+                    TsType::TsTypeLit(..) => true,
+                    TsType::TsTypeRef(TsTypeRef {
+                        type_name: TsEntityName::Ident(ident),
+                        ..
+                    }) => {
+                        let key = (ident.sym.clone(), ident.ctxt);
+                        self.interfaces.contains_key(&key) || self.type_aliases.contains_key(&key)
+                    }
+                    _ => false,
+                };
+                let mut slots = vec![];
+                if resolvable {
+                    self.resolve_type_elements(slots_type, &mut slots);
+                }
+                let names = slots
+                    .iter()
+                    .filter_map(|slot| slot.key())
+                    .map(|name| name.to_string())
+                    .collect();
+                Some((Expr::Ident(quote_ident!("Object").into()), names))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve payload of emit declared as property,
+    /// which can be function type like `change: (value: number) => void`
+    /// or labeled tuple type like `change: [value: number]`.
+    fn resolve_emit_payload(&self, ty: &TsType) -> Vec<EmitPayloadParam> {
+        match ty {
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+                params,
+                ..
+            })) => fn_params_to_payload(params),
+            TsType::TsTupleType(TsTupleType { elem_types, .. }) => elem_types
+                .iter()
+                .map_while(|TsTupleElement { label, ty, .. }| {
+                    let name = match label {
+                        Some(Pat::Ident(ident)) => Some(ident.sym.clone()),
+                        _ => None,
+                    };
+                    match &**ty {
+                        TsType::TsRestType(..) => None,
+                        TsType::TsOptionalType(TsOptionalType { type_ann, .. }) => {
+                            Some((name, Some(type_ann.clone()), true))
+                        }
+                        _ => Some((
+                            name,
+                            Some(ty.clone()),
+                            matches!(label, Some(Pat::Ident(ident)) if ident.optional),
+                        )),
+                    }
+                })
+                .collect(),
+            TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => {
+                self.resolve_emit_payload(type_ann)
+            }
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ident),
+                type_params,
+                ..
+            }) => self
+                .resolve_type_alias(&(ident.sym.clone(), ident.ctxt), type_params.as_deref())
+                .map(|aliased| self.resolve_emit_payload(&aliased))
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    /// Build runtime validator of emit payload,
+    /// for example, `(value) => typeof value === 'number'`.
+    /// Payloads of overloads are checked as union.
+    /// It returns `null` if there's nothing to validate.
+    fn build_emit_validator(&self, overloads: Vec<Vec<EmitPayloadParam>>) -> Expr {
+        // parameter names are kept only if overloads agree on them
+        let len = overloads.iter().map(Vec::len).max().unwrap_or_default();
+        let params = (0..len)
+            .map(|i| {
+                let mut names = overloads
+                    .iter()
+                    .filter_map(|payload| payload.get(i))
+                    .map(|(name, ..)| name);
+                let first = names.next().cloned().flatten();
+                match first {
+                    Some(name) if names.all(|other| other.as_ref() == Some(&name)) => {
+                        quote_ident!(name)
+                    }
+                    _ => quote_ident!(format!("arg{i}")),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut checks: Vec<Expr> = Vec::with_capacity(overloads.len());
+        for payload in overloads {
+            // any payload is valid if one of overloads can't be checked
+            let Some(check) = self.build_emit_payload_check(payload, &params) else {
+                return Expr::Lit(Lit::Null(Null { span: DUMMY_SP }));
+            };
+            if !checks.iter().any(|other| other.eq_ignore_span(&check)) {
+                checks.push(check);
+            }
+        }
+        let is_union = checks.len() > 1;
+        let Some(checks) = checks
+            .into_iter()
+            .map(|check| match check {
+                Expr::Bin(BinExpr {
+                    op: BinaryOp::LogicalAnd,
+                    ..
+                }) if is_union => Expr::Paren(ParenExpr {
+                    expr: Box::new(check),
+                    span: DUMMY_SP,
+                }),
+                _ => check,
+            })
+            .reduce(|a, b| build_bin_expr(a, BinaryOp::LogicalOr, b))
+        else {
+            return Expr::Lit(Lit::Null(Null { span: DUMMY_SP }));
+        };
+        Expr::Arrow(ArrowExpr {
+            params: params
+                .into_iter()
+                .map(|param| Pat::Ident(param.into()))
+                .collect(),
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(checks))),
+            ..Default::default()
+        })
+    }
+
+    /// Build runtime check of a single emit payload with the given parameter names,
+    /// where parameters not declared by this payload must be `undefined`.
+    /// It returns `None` if there's nothing to check.
+    fn build_emit_payload_check(
+        &self,
+        payload: Vec<EmitPayloadParam>,
+        params: &[IdentName],
+    ) -> Option<Expr> {
+        let declared = payload.len();
+        payload
+            .into_iter()
+            .zip(params)
+            .filter_map(|((_, ty, optional), param)| {
+                let ty = ty?;
+                let types = self.infer_runtime_type(&ty);
+                if types.contains(&RuntimeType::Unknown) {
+                    return None;
+                }
+                let check = types
+                    .into_iter()
+                    .map(|ty| {
+                        if let RuntimeType::Ctor(ty) = ty {
+                            build_runtime_type_check(param, &ty)
+                        } else {
+                            build_bin_expr(
+                                Expr::Ident(param.clone().into()),
+                                BinaryOp::EqEqEq,
+                                Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+                            )
+                        }
+                    })
+                    .reduce(|a, b| build_bin_expr(a, BinaryOp::LogicalOr, b))?;
+                Some(if optional {
+                    build_bin_expr(
+                        build_bin_expr(
+                            Expr::Ident(param.clone().into()),
+                            BinaryOp::EqEqEq,
+                            Expr::Ident(quote_ident!("undefined").into()),
+                        ),
+                        BinaryOp::LogicalOr,
+                        check,
+                    )
+                } else {
+                    check
+                })
+            })
+            .chain(params.iter().skip(declared).map(|param| {
+                build_bin_expr(
+                    Expr::Ident(param.clone().into()),
+                    BinaryOp::EqEqEq,
+                    Expr::Ident(quote_ident!("undefined").into()),
+                )
+            }))
+            .map(|check| match check {
+                Expr::Bin(BinExpr {
+                    op: BinaryOp::LogicalOr,
+                    ..
+                }) => Expr::Paren(ParenExpr {
+                    expr: Box::new(check),
+                    span: DUMMY_SP,
+                }),
+                _ => check,
+            })
+            .reduce(|a, b| build_bin_expr(a, BinaryOp::LogicalAnd, b))
+    }
+}
+
+/// Get the second parameter of setup function, which is setup context.
+pub(crate) fn get_setup_context_param(setup_fn: &ExprOrSpread) -> Option<&Pat> {
+    if let ExprOrSpread { expr, spread: None } = setup_fn {
+        match &**expr {
+            Expr::Arrow(arrow) => arrow.params.get(1),
+            Expr::Fn(fn_expr) => fn_expr.function.params.get(1).map(|param| &param.pat),
             _ => None,
         }
+    } else {
+        None
+    }
+}
+
+fn get_pat_type(pat: &Pat) -> Option<&TsType> {
+    match pat {
+        Pat::Ident(ident) => ident.type_ann.as_deref(),
+        Pat::Array(array) => array.type_ann.as_deref(),
+        Pat::Object(object) => object.type_ann.as_deref(),
+        _ => None,
+    }
+    .map(|type_ann| &*type_ann.type_ann)
+}
+
+/// Collect type parameters of setup function with their constraints,
+/// for example, `<T extends string>(props: { value: T }) => {}`.
+pub(crate) fn extract_type_params(
+    setup_fn: &ExprOrSpread,
+) -> FnvHashMap<(Atom, SyntaxContext), Option<Box<TsType>>> {
+    let type_params = match setup_fn {
+        ExprOrSpread { expr, spread: None } => match &**expr {
+            Expr::Arrow(arrow) => arrow.type_params.as_deref(),
+            Expr::Fn(fn_expr) => fn_expr.function.type_params.as_deref(),
+            _ => None,
+        },
+        _ => None,
+    };
+    type_params
+        .map(|TsTypeParamDecl { params, .. }| {
+            params
+                .iter()
+                .map(|param| {
+                    (
+                        (param.name.sym.clone(), param.name.ctxt),
+                        param.constraint.clone(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl RefinedTsTypeElement {
+    fn key(&self) -> Option<&Atom> {
+        match self {
+            RefinedTsTypeElement::Property(TsPropertySignature { key, .. })
+            | RefinedTsTypeElement::MethodSignature(TsMethodSignature { key, .. })
+            | RefinedTsTypeElement::GetterSignature(TsGetterSignature { key, .. }) => {
+                match &**key {
+                    Expr::Ident(ident) => Some(&ident.sym),
+                    Expr::Lit(Lit::Str(str)) => Some(&str.value),
+                    _ => None,
+                }
+            }
+            RefinedTsTypeElement::CallSignature(..) => None,
+        }
+    }
+}
+
+fn build_property_signature(key: Atom, ty: Option<TsType>, optional: bool) -> TsPropertySignature {
+    TsPropertySignature {
+        key: Box::new(if Ident::verify_symbol(&key).is_ok() {
+            Expr::Ident(quote_ident!(key).into())
+        } else {
+            Expr::Lit(Lit::Str(quote_str!(key)))
+        }),
+        computed: false,
+        optional,
+        readonly: false,
+        type_ann: ty.map(|ty| {
+            Box::new(TsTypeAnn {
+                type_ann: Box::new(ty),
+                span: DUMMY_SP,
+            })
+        }),
+        span: DUMMY_SP,
+    }
+}
+
+/// Runtime type of prop or emit payload inferred from TypeScript type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum RuntimeType {
+    /// Constructor like `String` or `Array`.
+    Ctor(Atom),
+    /// `null`, which accepts any value.
+    Null,
+    /// Type which can't be inferred, then runtime check of this prop will be skipped.
+    Unknown,
+}
+
+impl RuntimeType {
+    /// Build expression used as the `type` option of prop.
+    /// Unknown type is normalized before this, so it's built as `null` too.
+    fn into_expr(self) -> Expr {
+        match self {
+            RuntimeType::Ctor(ty) => Expr::Ident(quote_ident!(ty).into()),
+            RuntimeType::Null | RuntimeType::Unknown => {
+                Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))
+            }
+        }
+    }
+}
+
+fn infer_members_runtime_type(members: &[TsTypeElement]) -> IndexSet<RuntimeType> {
+    if members.is_empty() {
+        return IndexSet::from_iter([RuntimeType::Ctor(atom!("Object"))]);
+    }
+    members
+        .iter()
+        .map(|member| {
+            if let TsTypeElement::TsCallSignatureDecl(..)
+            | TsTypeElement::TsConstructSignatureDecl(..) = member
+            {
+                RuntimeType::Ctor(atom!("Function"))
+            } else {
+                RuntimeType::Ctor(atom!("Object"))
+            }
+        })
+        .collect()
+}
+
+/// Normalize runtime types containing unknown type in the same way as `@vue/compiler-sfc`.
+/// If there're `Boolean` or `Function`, unknown type will be removed with skipping runtime check,
+/// so boolean casting and function props still work; otherwise, the runtime type will be `null`.
+/// It returns `true` if runtime check should be skipped.
+fn normalize_runtime_types(types: &mut IndexSet<RuntimeType>) -> bool {
+    if !types.contains(&RuntimeType::Unknown) {
+        return false;
+    }
+    if types.contains(&RuntimeType::Ctor(atom!("Boolean")))
+        || types.contains(&RuntimeType::Ctor(atom!("Function")))
+    {
+        types.shift_remove(&RuntimeType::Unknown);
+        true
+    } else {
+        types.clear();
+        types.insert(RuntimeType::Null);
+        false
+    }
+}
+
+/// Name, type and optionality of emit payload parameter.
+type EmitPayloadParam = (Option<Atom>, Option<Box<TsType>>, bool);
+
+fn fn_params_to_payload(params: &[TsFnParam]) -> Vec<EmitPayloadParam> {
+    params
+        .iter()
+        .map_while(|param| match param {
+            TsFnParam::Ident(ident) => Some((
+                Some(ident.sym.clone()),
+                ident
+                    .type_ann
+                    .as_ref()
+                    .map(|type_ann| type_ann.type_ann.clone()),
+                ident.optional,
+            )),
+            TsFnParam::Array(ArrayPat { type_ann, .. })
+            | TsFnParam::Object(ObjectPat { type_ann, .. }) => Some((
+                None,
+                type_ann.as_ref().map(|type_ann| type_ann.type_ann.clone()),
+                false,
+            )),
+            TsFnParam::Rest(..) => None,
+        })
+        .collect()
+}
+
+fn build_runtime_type_check(param: &IdentName, ty: &Atom) -> Expr {
+    let param = Expr::Ident(param.clone().into());
+    let type_of = |name: &str| {
+        build_bin_expr(
+            Expr::Unary(UnaryExpr {
+                op: UnaryOp::TypeOf,
+                arg: Box::new(param.clone()),
+                span: DUMMY_SP,
+            }),
+            BinaryOp::EqEqEq,
+            Expr::Lit(Lit::Str(quote_str!(name))),
+        )
+    };
+    match &**ty {
+        "String" => type_of("string"),
+        "Number" => type_of("number"),
+        "Boolean" => type_of("boolean"),
+        "Symbol" => type_of("symbol"),
+        "BigInt" => type_of("bigint"),
+        "Function" => type_of("function"),
+        "Object" => build_bin_expr(
+            build_bin_expr(
+                param.clone(),
+                BinaryOp::NotEqEq,
+                Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            ),
+            BinaryOp::LogicalAnd,
+            type_of("object"),
+        ),
+        "Array" => Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(quote_ident!("Array").into())),
+                prop: MemberProp::Ident(quote_ident!("isArray")),
+                span: DUMMY_SP,
+            }))),
+            args: vec![ExprOrSpread {
+                expr: Box::new(param),
+                spread: None,
+            }],
+            ..Default::default()
+        }),
+        _ => build_bin_expr(
+            param,
+            BinaryOp::InstanceOf,
+            Expr::Ident(quote_ident!(ty.clone()).into()),
+        ),
+    }
+}
+
+fn build_bin_expr(left: Expr, op: BinaryOp, right: Expr) -> Expr {
+    Expr::Bin(BinExpr {
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+        span: DUMMY_SP,
+    })
+}
+
+/// Evaluate intrinsic string manipulation types like `Capitalize<'foo'>`.
+fn manipulate_string(utility: &str, str: &str) -> Atom {
+    match utility {
+        "Uppercase" => Atom::from(str.to_uppercase()),
+        "Lowercase" => Atom::from(str.to_lowercase()),
+        "Capitalize" | "Uncapitalize" => {
+            let mut chars = str.chars();
+            if let Some(first) = chars.next() {
+                let first = if utility == "Capitalize" {
+                    first.to_uppercase().collect::<String>()
+                } else {
+                    first.to_lowercase().collect::<String>()
+                };
+                Atom::from(first + chars.as_str())
+            } else {
+                Atom::default()
+            }
+        }
+        _ => Atom::from(str),
+    }
+}
+
+fn build_keyword_type(kind: TsKeywordTypeKind) -> TsType {
+    TsType::TsKeywordType(TsKeywordType {
+        kind,
+        span: DUMMY_SP,
+    })
+}
+
+fn build_global_type_ref(name: &str) -> TsType {
+    TsType::TsTypeRef(TsTypeRef {
+        type_name: TsEntityName::Ident(quote_ident!(name).into()),
+        type_params: None,
+        span: DUMMY_SP,
+    })
+}
+
+fn build_promise_type(ty: TsType) -> TsType {
+    TsType::TsTypeRef(TsTypeRef {
+        type_name: TsEntityName::Ident(quote_ident!("Promise").into()),
+        type_params: Some(Box::new(TsTypeParamInstantiation {
+            params: vec![Box::new(ty)],
+            span: DUMMY_SP,
+        })),
+        span: DUMMY_SP,
+    })
+}
+
+fn build_fn_type<'a>(params: impl Iterator<Item = &'a Pat>, return_type: Option<TsType>) -> TsType {
+    TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(TsFnType {
+        params: params
+            .filter_map(|pat| match pat {
+                Pat::Ident(ident) => Some(TsFnParam::Ident(ident.clone())),
+                Pat::Array(array) => Some(TsFnParam::Array(array.clone())),
+                Pat::Rest(rest) => Some(TsFnParam::Rest(rest.clone())),
+                Pat::Object(object) => Some(TsFnParam::Object(object.clone())),
+                Pat::Assign(AssignPat { left, .. }) => match &**left {
+                    Pat::Ident(ident) => Some(TsFnParam::Ident(ident.clone())),
+                    Pat::Array(array) => Some(TsFnParam::Array(array.clone())),
+                    Pat::Object(object) => Some(TsFnParam::Object(object.clone())),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        type_params: None,
+        type_ann: Box::new(TsTypeAnn {
+            type_ann: Box::new(
+                return_type
+                    .unwrap_or_else(|| build_keyword_type(TsKeywordTypeKind::TsUnknownKeyword)),
+            ),
+            span: DUMMY_SP,
+        }),
+        span: DUMMY_SP,
+    }))
+}
+
+fn prop_name_to_atom(prop_name: &PropName) -> Option<Atom> {
+    match prop_name {
+        PropName::Ident(ident) => Some(ident.sym.clone()),
+        PropName::Str(str) => Some(str.value.clone()),
+        PropName::Num(num) => Some(Atom::from(num.value.to_string())),
+        _ => None,
+    }
+}
+
+fn build_type_lit(elements: Vec<RefinedTsTypeElement>) -> TsType {
+    TsType::TsTypeLit(TsTypeLit {
+        members: elements
+            .into_iter()
+            .map(|element| match element {
+                RefinedTsTypeElement::Property(property) => {
+                    TsTypeElement::TsPropertySignature(property)
+                }
+                RefinedTsTypeElement::GetterSignature(getter) => {
+                    TsTypeElement::TsGetterSignature(getter)
+                }
+                RefinedTsTypeElement::MethodSignature(method) => {
+                    TsTypeElement::TsMethodSignature(method)
+                }
+                RefinedTsTypeElement::CallSignature(call) => {
+                    TsTypeElement::TsCallSignatureDecl(call)
+                }
+            })
+            .collect(),
+        span: DUMMY_SP,
+    })
+}
+
+/// Replace references of type parameters with the given types.
+fn substitute_type_params(
+    ty: &TsType,
+    replacements: &FnvHashMap<(Atom, SyntaxContext), TsType>,
+) -> TsType {
+    struct TypeParamsReplacer<'a> {
+        replacements: &'a FnvHashMap<(Atom, SyntaxContext), TsType>,
+    }
+
+    impl VisitMut for TypeParamsReplacer<'_> {
+        fn visit_mut_ts_type(&mut self, ty: &mut TsType) {
+            if let TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(ident),
+                type_params: None,
+                ..
+            }) = ty
+            {
+                if let Some(replacement) = self.replacements.get(&(ident.sym.clone(), ident.ctxt)) {
+                    *ty = replacement.clone();
+                    return;
+                }
+            }
+            ty.visit_mut_children_with(self);
+        }
+    }
+
+    let mut ty = ty.clone();
+    ty.visit_mut_with(&mut TypeParamsReplacer { replacements });
+    ty
+}
+
+fn is_primitive_keyword(kind: TsKeywordTypeKind) -> bool {
+    matches!(
+        kind,
+        TsKeywordTypeKind::TsStringKeyword
+            | TsKeywordTypeKind::TsNumberKeyword
+            | TsKeywordTypeKind::TsBooleanKeyword
+            | TsKeywordTypeKind::TsBigIntKeyword
+            | TsKeywordTypeKind::TsSymbolKeyword
+            | TsKeywordTypeKind::TsNullKeyword
+            | TsKeywordTypeKind::TsUndefinedKeyword
+    )
+}
+
+fn infer_enum_member_type(member: &TsEnumMember) -> RuntimeType {
+    if is_string_expr(member.init.as_deref()) {
+        RuntimeType::Ctor(atom!("String"))
+    } else {
+        // members without initializers are auto-incremented numbers
+        RuntimeType::Ctor(atom!("Number"))
+    }
+}
+
+fn is_string_expr(expr: Option<&Expr>) -> bool {
+    match expr {
+        Some(Expr::Lit(Lit::Str(..)) | Expr::Tpl(..)) => true,
+        Some(Expr::Bin(BinExpr {
+            op: op!(bin, "+"),
+            left,
+            right,
+            ..
+        })) => is_string_expr(Some(left)) || is_string_expr(Some(right)),
+        Some(Expr::Paren(ParenExpr { expr, .. })) => is_string_expr(Some(expr)),
+        _ => false,
+    }
+}
+
+fn enum_member_string_value(member: &TsEnumMember) -> Option<Atom> {
+    if let Some(Expr::Lit(Lit::Str(str))) = member.init.as_deref() {
+        Some(str.value.clone())
+    } else {
+        None
     }
 }
 
@@ -1202,7 +3012,7 @@ fn extract_prop_name(expr: Expr, computed: bool) -> PropName {
     }
 }
 
-fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<PropName>> {
+fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<'_, PropName>> {
     match prop_name {
         PropName::Ident(..) | PropName::Str(..) | PropName::Num(..) | PropName::BigInt(..) => {
             Some(Cow::Borrowed(prop_name))
@@ -1217,6 +3027,35 @@ fn try_unwrap_lit_prop_name(prop_name: &PropName) -> Option<Cow<PropName>> {
     }
 }
 
+/// Unwrap default factory generated by [`build_default_value`] for function type props,
+/// since Vue doesn't call default of `Function` props, like what compiler-sfc does.
+fn unwrap_default_factory(default: &Expr) -> &Expr {
+    match default {
+        Expr::Arrow(ArrowExpr { params, body, .. }) if params.is_empty() => match &**body {
+            BlockStmtOrExpr::Expr(expr) => expr,
+            _ => default,
+        },
+        _ => default,
+    }
+}
+
+/// Non-literal default value will be wrapped in factory function,
+/// so objects and arrays won't be shared between component instances.
+fn build_default_value(value: &Expr) -> Expr {
+    if value.is_lit() {
+        value.clone()
+    } else {
+        Expr::Arrow(ArrowExpr {
+            params: vec![],
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(value.clone()))),
+            is_async: false,
+            is_generator: false,
+            span: DUMMY_SP,
+            ..Default::default()
+        })
+    }
+}
+
 fn extract_type_ann_from_pat(pat: &Pat) -> Option<&TsTypeAnn> {
     match pat {
         Pat::Ident(ident) => ident.type_ann.as_deref(),
@@ -0,0 +1,404 @@
+use crate::options::TypeResolverOptions;
+use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
+use indexmap::IndexMap;
+use std::{
+    cell::RefCell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::SystemTime,
+};
+use swc_core::{
+    common::{sync::Lrc, FileName, Mark, SourceMap, SyntaxContext, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::{atom, Atom},
+        parser::{parse_file_as_module, Syntax, TsSyntax},
+        transforms::base::resolver,
+        visit::{Visit, VisitMutWith, VisitWith},
+    },
+};
+
+type Exports = FnvHashMap<Atom, (Atom, SyntaxContext)>;
+
+/// Max number of parsed modules kept in cache,
+/// so long-running processes like dev servers won't keep all modules forever.
+const MAX_PARSED_MODULES: usize = 256;
+
+thread_local! {
+    /// Parsed modules with their modification time, shared by all transformed files in current thread,
+    /// so the same file won't be read and parsed repeatedly.
+    /// Modules are ordered by last use, and the least recently used one is evicted when it's full.
+    static PARSED_MODULES: RefCell<IndexMap<PathBuf, (SystemTime, Module), FnvBuildHasher>> =
+        Default::default();
+}
+
+/// Type declarations collected from other files.
+pub(crate) struct TypeDecls<'a> {
+    pub(crate) interfaces: &'a mut FnvHashMap<(Atom, SyntaxContext), TsInterfaceDecl>,
+    pub(crate) type_aliases: &'a mut FnvHashMap<(Atom, SyntaxContext), TsType>,
+    pub(crate) type_alias_params: &'a mut FnvHashMap<(Atom, SyntaxContext), TsTypeParamDecl>,
+    pub(crate) enums: &'a mut FnvHashMap<(Atom, SyntaxContext), TsEnumDecl>,
+}
+
+/// Resolve types imported from other files by reading and parsing them from local file system.
+pub(crate) struct TypeResolver {
+    options: TypeResolverOptions,
+    unresolved_mark: Mark,
+    source_map: Lrc<SourceMap>,
+    /// Exported types of each loaded file.
+    /// The value will be `None` if that file is being loaded or it can't be loaded.
+    modules: FnvHashMap<PathBuf, Option<Rc<Exports>>>,
+}
+
+impl TypeResolver {
+    pub(crate) fn new(options: TypeResolverOptions, unresolved_mark: Mark) -> Self {
+        Self {
+            options,
+            unresolved_mark,
+            source_map: Default::default(),
+            modules: Default::default(),
+        }
+    }
+
+    /// Resolve the type exported as `name` from module `specifier`,
+    /// returning the key of declaration which can be looked up in [`TypeDecls`].
+    pub(crate) fn resolve(
+        &mut self,
+        importer: &Path,
+        specifier: &str,
+        name: &Atom,
+        decls: &mut TypeDecls,
+    ) -> Option<(Atom, SyntaxContext)> {
+        let path = self.resolve_module_path(importer, specifier)?;
+        self.load(path, decls)?.get(name).cloned()
+    }
+
+    fn resolve_module_path(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        if specifier.starts_with("./") || specifier.starts_with("../") {
+            return try_extensions(&importer.parent()?.join(specifier));
+        }
+
+        let root = Path::new(self.options.tsconfig_dir.as_deref().unwrap_or_default());
+        let base_url = self
+            .options
+            .base_url
+            .as_deref()
+            .map(|base_url| root.join(base_url));
+        self.options
+            .paths
+            .iter()
+            .find_map(|(pattern, targets)| {
+                let captured = if let Some((prefix, suffix)) = pattern.split_once('*') {
+                    specifier.strip_prefix(prefix)?.strip_suffix(suffix)?
+                } else if pattern == specifier {
+                    ""
+                } else {
+                    return None;
+                };
+                targets.iter().find_map(|target| {
+                    let target = target.replacen('*', captured, 1);
+                    // `paths` are relative to tsconfig if `baseUrl` isn't specified
+                    try_extensions(&base_url.as_deref().unwrap_or(root).join(target))
+                })
+            })
+            .or_else(|| {
+                base_url
+                    .as_deref()
+                    .and_then(|base_url| try_extensions(&base_url.join(specifier)))
+            })
+    }
+
+    fn load(&mut self, path: PathBuf, decls: &mut TypeDecls) -> Option<Rc<Exports>> {
+        if let Some(exports) = self.modules.get(&path) {
+            return exports.clone();
+        }
+        // mark as loading first to avoid infinite loop caused by circular imports
+        self.modules.insert(path.clone(), None);
+
+        let exports = self
+            .parse_module(&path)
+            .map(|module| Rc::new(self.collect_exports(&path, module, decls)));
+        self.modules.insert(path, exports.clone());
+        exports
+    }
+
+    fn parse_module(&self, path: &Path) -> Option<Module> {
+        let modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        let cached = PARSED_MODULES.with_borrow_mut(|modules| {
+            let index = modules.get_index_of(path)?;
+            let last = modules.len() - 1;
+            modules.move_index(index, last);
+            let (time, module) = &modules[last];
+            if *time == modified {
+                Some(module.clone())
+            } else {
+                // the file has been changed
+                modules.pop();
+                None
+            }
+        });
+        let mut module = if let Some(module) = cached {
+            module
+        } else {
+            let module = self.read_module(path)?;
+            PARSED_MODULES.with_borrow_mut(|modules| {
+                modules.insert(path.to_path_buf(), (modified, module.clone()));
+                if modules.len() > MAX_PARSED_MODULES {
+                    modules.shift_remove_index(0);
+                }
+            });
+            module
+        };
+        // each file has its own top level mark, so types with same name in different files won't conflict
+        module.visit_mut_with(&mut resolver(self.unresolved_mark, Mark::new(), true));
+        Some(module)
+    }
+
+    fn read_module(&self, path: &Path) -> Option<Module> {
+        let code = fs::read_to_string(path).ok()?;
+        let file_name = path.to_string_lossy();
+        let source_file = self
+            .source_map
+            .new_source_file(Lrc::new(FileName::Real(path.to_path_buf())), code);
+        parse_file_as_module(
+            &source_file,
+            Syntax::Typescript(TsSyntax {
+                tsx: file_name.ends_with(".tsx"),
+                dts: [".d.ts", ".d.mts", ".d.cts"]
+                    .iter()
+                    .any(|ext| file_name.ends_with(ext)),
+                ..Default::default()
+            }),
+            EsVersion::latest(),
+            None,
+            &mut vec![],
+        )
+        .ok()
+    }
+
+    fn collect_exports(&mut self, path: &Path, module: Module, decls: &mut TypeDecls) -> Exports {
+        let mut exports = Exports::default();
+        let mut export_all_sources = vec![];
+        let type_references = collect_type_references(&module);
+
+        module.body.into_iter().for_each(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                collect_decl(decl, decls);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                if let Some(key) = collect_decl(decl, decls) {
+                    exports.insert(key.0.clone(), key);
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::TsInterfaceDecl(interface),
+                ..
+            })) => {
+                if let Some(key) = collect_decl(Decl::TsInterface(interface), decls) {
+                    exports.insert(atom!("default"), key);
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                specifiers,
+                src,
+                type_only,
+                ..
+            })) => {
+                specifiers.into_iter().for_each(|specifier| {
+                    let (local, imported) = match specifier {
+                        ImportSpecifier::Named(ImportNamedSpecifier {
+                            local,
+                            imported,
+                            is_type_only,
+                            ..
+                        }) => {
+                            if !type_only
+                                && !is_type_only
+                                && !type_references.contains(&(local.sym.clone(), local.ctxt))
+                            {
+                                return;
+                            }
+                            let imported = match imported {
+                                Some(ModuleExportName::Ident(ident)) => ident.sym,
+                                Some(ModuleExportName::Str(str)) => str.value,
+                                None => local.sym.clone(),
+                            };
+                            (local, imported)
+                        }
+                        ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                            if !type_only
+                                && !type_references.contains(&(local.sym.clone(), local.ctxt))
+                            {
+                                return;
+                            }
+                            (local, atom!("default"))
+                        }
+                        ImportSpecifier::Namespace(..) => return,
+                    };
+                    if let Some(key) = self.resolve(path, &src.value, &imported, decls) {
+                        decls
+                            .type_aliases
+                            .insert((local.sym, local.ctxt), build_type_ref(key));
+                    }
+                });
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                specifiers,
+                src,
+                ..
+            })) => {
+                specifiers.into_iter().for_each(|specifier| {
+                    let ExportSpecifier::Named(ExportNamedSpecifier { orig, exported, .. }) =
+                        specifier
+                    else {
+                        return;
+                    };
+                    let ModuleExportName::Ident(orig) = orig else {
+                        return;
+                    };
+                    let exported = match exported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym,
+                        Some(ModuleExportName::Str(str)) => str.value,
+                        None => orig.sym.clone(),
+                    };
+                    if let Some(src) = &src {
+                        if let Some(key) = self.resolve(path, &src.value, &orig.sym, decls) {
+                            exports.insert(exported, key);
+                        }
+                    } else {
+                        exports.insert(exported, (orig.sym, orig.ctxt));
+                    }
+                });
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { src, .. })) => {
+                export_all_sources.push(src);
+            }
+            _ => {}
+        });
+
+        export_all_sources.into_iter().for_each(|src| {
+            let Some(path) = self.resolve_module_path(path, &src.value) else {
+                return;
+            };
+            if let Some(reexports) = self.load(path, decls) {
+                reexports
+                    .iter()
+                    .filter(|(name, _)| *name != "default")
+                    .for_each(|(name, key)| {
+                        exports.entry(name.clone()).or_insert_with(|| key.clone());
+                    });
+            }
+        });
+
+        exports
+    }
+}
+
+fn collect_decl(decl: Decl, decls: &mut TypeDecls) -> Option<(Atom, SyntaxContext)> {
+    match decl {
+        Decl::TsInterface(interface) => {
+            let key = (interface.id.sym.clone(), interface.id.ctxt);
+            if let Some(existing) = decls.interfaces.get_mut(&key) {
+                existing.body.body.extend(interface.body.body);
+            } else {
+                decls.interfaces.insert(key.clone(), *interface);
+            }
+            Some(key)
+        }
+        Decl::TsEnum(ts_enum) => {
+            let key = (ts_enum.id.sym.clone(), ts_enum.id.ctxt);
+            if let Some(existing) = decls.enums.get_mut(&key) {
+                existing.members.extend(ts_enum.members);
+            } else {
+                decls.enums.insert(key.clone(), *ts_enum);
+            }
+            Some(key)
+        }
+        Decl::TsTypeAlias(alias) => {
+            let key = (alias.id.sym.clone(), alias.id.ctxt);
+            if let Some(type_params) = alias.type_params {
+                decls.type_alias_params.insert(key.clone(), *type_params);
+            }
+            decls.type_aliases.insert(key.clone(), *alias.type_ann);
+            Some(key)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn build_type_ref((sym, ctxt): (Atom, SyntaxContext)) -> TsType {
+    TsType::TsTypeRef(TsTypeRef {
+        type_name: TsEntityName::Ident(Ident::new(sym, DUMMY_SP, ctxt)),
+        type_params: None,
+        span: DUMMY_SP,
+    })
+}
+
+/// Collect identifiers referenced in types, including `typeof` queries and `extends` of interfaces,
+/// so only imports used as types will be resolved.
+pub(crate) fn collect_type_references(module: &Module) -> FnvHashSet<(Atom, SyntaxContext)> {
+    let mut collector = TypeReferenceCollector::default();
+    module.visit_with(&mut collector);
+    collector.references
+}
+
+#[derive(Default)]
+struct TypeReferenceCollector {
+    references: FnvHashSet<(Atom, SyntaxContext)>,
+}
+
+impl Visit for TypeReferenceCollector {
+    fn visit_ts_entity_name(&mut self, entity_name: &TsEntityName) {
+        match entity_name {
+            TsEntityName::Ident(ident) => {
+                self.references.insert((ident.sym.clone(), ident.ctxt));
+            }
+            TsEntityName::TsQualifiedName(name) => name.left.visit_with(self),
+        }
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, expr: &TsExprWithTypeArgs) {
+        expr.visit_children_with(self);
+        if let Expr::Ident(ident) = &*expr.expr {
+            self.references.insert((ident.sym.clone(), ident.ctxt));
+        }
+    }
+}
+
+fn try_extensions(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_str()?;
+    if path.is_file()
+        && [".ts", ".tsx", ".mts", ".cts"]
+            .iter()
+            .any(|ext| file_name.ends_with(ext))
+    {
+        return Some(path.to_path_buf());
+    }
+
+    // support ESM-style imports like `import type { Props } from './types.js'`
+    let (stem, exts): (_, &[_]) = if let Some(stem) = file_name.strip_suffix(".mjs") {
+        (stem, &[".mts", ".d.mts"])
+    } else if let Some(stem) = file_name.strip_suffix(".cjs") {
+        (stem, &[".cts", ".d.cts"])
+    } else {
+        let stem = file_name
+            .strip_suffix(".js")
+            .or_else(|| file_name.strip_suffix(".jsx"))
+            .unwrap_or(file_name);
+        (
+            stem,
+            &[".ts", ".tsx", ".d.ts", ".mts", ".d.mts", ".cts", ".d.cts"],
+        )
+    };
+    exts.iter()
+        .map(|ext| path.with_file_name(format!("{stem}{ext}")))
+        .chain(
+            ["index.ts", "index.tsx", "index.d.ts"]
+                .iter()
+                .map(|index| path.join(index)),
+        )
+        .find(|path| path.is_file())
+}
@@ -1,8 +1,13 @@
+use fnv::{FnvHashMap, FnvHashSet};
+use indexmap::IndexSet;
+use std::{borrow::Cow, path::Path, sync::OnceLock};
 use swc_core::{
-    common::DUMMY_SP,
+    common::{SyntaxContext, DUMMY_SP},
     ecma::{
         ast::*,
+        atoms::Atom,
         utils::{private_ident, quote_ident, quote_str},
+        visit::{Visit, VisitWith},
     },
 };
 
@@ -127,6 +132,45 @@ fn is_constant(expr: &Expr) -> bool {
     }
 }
 
+pub(crate) fn jsx_attr_name(jsx_attr: &JSXAttr) -> Cow<'_, str> {
+    match &jsx_attr.name {
+        JSXAttrName::Ident(ident) => Cow::from(&*ident.sym),
+        JSXAttrName::JSXNamespacedName(name) => {
+            Cow::from(format!("{}:{}", name.ns.sym, name.name.sym))
+        }
+    }
+}
+
+/// Check if element is `<textarea>` or `<input>` whose `type` is absent or a text-like type.
+pub(crate) fn is_text_input(opening: &JSXOpeningElement) -> bool {
+    let JSXElementName::Ident(tag) = &opening.name else {
+        return false;
+    };
+    match &*tag.sym {
+        "textarea" => true,
+        // the last one wins if `type` is specified multiple times or overridden by spread
+        "input" => opening
+            .attrs
+            .iter()
+            .rev()
+            .find_map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(jsx_attr) if jsx_attr_name(jsx_attr) == "type" => {
+                    Some(match &jsx_attr.value {
+                        Some(JSXAttrValue::Lit(Lit::Str(str))) => matches!(
+                            &*str.value,
+                            "text" | "search" | "email" | "url" | "tel" | "password" | "number"
+                        ),
+                        _ => false,
+                    })
+                }
+                JSXAttrOrSpread::SpreadElement(..) => Some(false),
+                _ => None,
+            })
+            .unwrap_or(true),
+        _ => false,
+    }
+}
+
 pub(crate) fn is_on(attr_name: &str) -> bool {
     match attr_name.as_bytes() {
         [b'o', b'n', c, ..] => !c.is_ascii_lowercase(),
@@ -174,7 +218,7 @@ pub(crate) fn dedupe_props(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
                                     expr: value,
                                 }));
                             } else {
-                                *defined_value = Box::new(Expr::Array(ArrayLit {
+                                **defined_value = Expr::Array(ArrayLit {
                                     span: DUMMY_SP,
                                     elems: vec![
                                         Some(ExprOrSpread {
@@ -186,7 +230,7 @@ pub(crate) fn dedupe_props(props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
                                             expr: value,
                                         }),
                                     ],
-                                }));
+                                });
                             }
                         }
                         Some(..) => {}
@@ -271,14 +315,17 @@ pub(crate) fn transform_text(text: &str) -> String {
 
     let mut lines = vec![];
     while let Some((index, line)) = jsx_text_lines.next() {
-        let line = if index == 0 {
+        let line = if index == 0 && jsx_text_lines.peek().is_none() {
+            // single line text keeps its whitespace like `Hello {name}`
+            line
+        } else if index == 0 {
             // first line
-            line.trim_end()
+            line.trim_end_matches(' ')
         } else if jsx_text_lines.peek().is_none() {
             // last line
-            line.trim_start()
+            line.trim_start_matches(' ')
         } else {
-            line.trim()
+            line.trim_matches(' ')
         };
         if !line.is_empty() {
             lines.push(line);
@@ -286,3 +333,407 @@ pub(crate) fn transform_text(text: &str) -> String {
     }
     lines.join(" ")
 }
+
+/// Get value of JSX text with HTML entities decoded from its raw text.
+pub(crate) fn jsx_text_value(jsx_text: &JSXText) -> Cow<'_, str> {
+    if jsx_text.raw.is_empty() {
+        Cow::Borrowed(&jsx_text.value)
+    } else {
+        decode_entities(&jsx_text.raw)
+    }
+}
+
+/// Get value of JSX string attribute with HTML entities decoded from its raw text.
+pub(crate) fn jsx_attr_str_value(str: &Str) -> Cow<'_, str> {
+    // strip quotes of raw text
+    match str.raw.as_deref().and_then(|raw| {
+        raw.strip_prefix(['"', '\''])
+            .and_then(|raw| raw.strip_suffix(['"', '\'']))
+    }) {
+        Some(raw) => decode_entities(raw),
+        None => Cow::Borrowed(&str.value),
+    }
+}
+
+/// Decode HTML entities like `&nbsp;`, `&check;` and `&#x2014;` with the full HTML5 named entities table.
+/// Like JSX, entities must be terminated by semicolon, and unknown entities are kept as-is.
+pub(crate) fn decode_entities(text: &str) -> Cow<'_, str> {
+    // `&CounterClockwiseContourIntegral;` is the longest named entity
+    const MAX_ENTITY_LEN: usize = 32;
+
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        if let Some((chars, end)) = rest
+            .find(';')
+            .filter(|end| *end <= MAX_ENTITY_LEN)
+            .and_then(|end| decode_entity(&rest[..end]).map(|chars| (chars, end)))
+        {
+            decoded.push_str(&chars);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+        }
+    }
+    decoded.push_str(rest);
+    Cow::Owned(decoded)
+}
+
+fn decode_entity(name: &str) -> Option<Cow<'static, str>> {
+    if let Some(num) = name.strip_prefix('#') {
+        let code = if let Some(hex) = num.strip_prefix(['x', 'X']) {
+            hex.chars()
+                .all(|c| c.is_ascii_hexdigit())
+                .then(|| u32::from_str_radix(hex, 16).ok())??
+        } else {
+            num.chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| num.parse().ok())??
+        };
+        char::from_u32(code).map(|c| Cow::Owned(c.to_string()))
+    } else {
+        static NAMED_ENTITIES: OnceLock<FnvHashMap<&str, &str>> = OnceLock::new();
+        NAMED_ENTITIES
+            .get_or_init(|| {
+                entities::ENTITIES
+                    .iter()
+                    .filter_map(|entity| {
+                        let name = entity.entity.strip_prefix('&')?.strip_suffix(';')?;
+                        Some((name, entity.characters))
+                    })
+                    .collect()
+            })
+            .get(name)
+            .map(|chars| Cow::Borrowed(*chars))
+    }
+}
+
+/// Check if expression as JSX child is always a primitive value which will be rendered as text,
+/// so it can be compiled with `toDisplayString`.
+/// Identifiers are only included if they're known to hold primitive values by `text_bindings`,
+/// and member expressions are excluded since they may hold vnodes or arrays of vnodes.
+pub(crate) fn is_text_like_expr(
+    expr: &Expr,
+    text_bindings: &FnvHashSet<(Atom, SyntaxContext)>,
+) -> bool {
+    match expr {
+        Expr::Ident(ident) => text_bindings.contains(&(ident.sym.clone(), ident.ctxt)),
+        Expr::Lit(Lit::Str(..) | Lit::Num(..) | Lit::BigInt(..)) => true,
+        Expr::Tpl(..) => true,
+        // arithmetic operators always produce strings or numbers
+        Expr::Bin(BinExpr {
+            op: op!(bin, "+") | op!(bin, "-") | op!("*") | op!("/") | op!("%") | op!("**"),
+            ..
+        }) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-") | op!(unary, "+"),
+            ..
+        }) => true,
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. }) => is_text_like_expr(expr, text_bindings),
+        _ => false,
+    }
+}
+
+/// Check if type annotation guarantees a primitive value which will be rendered as text,
+/// like `string`, `number` or union of string and number literals.
+/// `boolean` is excluded since `true` and `false` children render nothing.
+pub(crate) fn is_text_type(ty: &TsType) -> bool {
+    match ty {
+        TsType::TsKeywordType(TsKeywordType { kind, .. }) => matches!(
+            kind,
+            TsKeywordTypeKind::TsStringKeyword
+                | TsKeywordTypeKind::TsNumberKeyword
+                | TsKeywordTypeKind::TsBigIntKeyword
+        ),
+        TsType::TsLitType(TsLitType { lit, .. }) => !matches!(lit, TsLit::Bool(..)),
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+            TsUnionType { types, .. },
+        )) => types.iter().all(|ty| is_text_type(ty)),
+        TsType::TsParenthesizedType(TsParenthesizedType { type_ann, .. }) => is_text_type(type_ann),
+        _ => false,
+    }
+}
+
+/// Find the `name` option of `defineComponent` call, which can be specified in
+/// options object as the first argument or the second argument after setup function.
+pub(crate) fn find_component_name(call: &CallExpr) -> Option<&Atom> {
+    let options = match call.args.first()? {
+        ExprOrSpread {
+            expr, spread: None, ..
+        } if expr.is_object() => expr,
+        _ => &call.args.get(1).filter(|arg| arg.spread.is_none())?.expr,
+    };
+    options.as_object()?.props.iter().find_map(|prop| {
+        if let Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(key),
+            value,
+        }) = prop.as_prop()?.as_ref()
+        {
+            match value.as_lit() {
+                Some(Lit::Str(str)) if key.sym == "name" => Some(&str.value),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    })
+}
+
+/// Infer component name from file path, just like what Vue does for SFC:
+/// `components/foo-bar.tsx` becomes `FooBar`, and `Foo/index.tsx` becomes `Foo`.
+pub(crate) fn infer_name_from_file(file_name: &str) -> Option<String> {
+    let path = Path::new(file_name);
+    let stem = path.file_stem()?.to_str()?;
+    let stem = if stem == "index" {
+        path.parent()?.file_name()?.to_str()?
+    } else {
+        stem
+    };
+    let name = stem
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '$')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+    if name.is_empty() || name.as_bytes()[0].is_ascii_digit() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Make file path relative to the given working directory with forward slashes,
+/// so it's the same across machines.
+pub(crate) fn relative_file_path(file_name: &str, cwd: Option<&str>) -> String {
+    let path = Path::new(file_name);
+    let path = cwd
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Hash relative file path to 8 hex chars as scope ID,
+/// which is the same as the one generated by `@vitejs/plugin-vue` for SFC in development mode.
+pub(crate) fn hash_file_path(file_name: &str, cwd: Option<&str>) -> String {
+    use sha2::{Digest, Sha256};
+
+    let hash = Sha256::digest(relative_file_path(file_name, cwd).as_bytes());
+    hash[..4].iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Collect names of functional components declared by the given declaration,
+/// that is, functions whose name starts with an uppercase letter and contain JSX.
+pub(crate) fn collect_functional_components(decl: &Decl) -> Vec<Ident> {
+    match decl {
+        Decl::Fn(FnDecl {
+            ident, function, ..
+        }) if is_component_name(&ident.sym) && contains_jsx(&**function) => {
+            vec![ident.clone()]
+        }
+        Decl::Var(var_decl) => var_decl
+            .decls
+            .iter()
+            .filter_map(|decl| match (&decl.name, decl.init.as_deref()) {
+                (
+                    Pat::Ident(BindingIdent { id, .. }),
+                    Some(init @ (Expr::Arrow(..) | Expr::Fn(..))),
+                ) if is_component_name(&id.sym) && contains_jsx(init) => Some(id.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Get name of default exported functional component like `export default function Foo() {}`.
+pub(crate) fn collect_default_exported_functional_component(decl: &DefaultDecl) -> Option<Ident> {
+    match decl {
+        DefaultDecl::Fn(FnExpr {
+            ident: Some(ident),
+            function,
+        }) if is_component_name(&ident.sym) && contains_jsx(&**function) => Some(ident.clone()),
+        _ => None,
+    }
+}
+
+pub(crate) fn build_display_name_assignment(ident: Ident) -> Stmt {
+    let name = ident.sym.clone();
+    Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: Box::new(Expr::Ident(ident)),
+                prop: MemberProp::Ident(quote_ident!("displayName")),
+            })),
+            right: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
+        })),
+    })
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.as_bytes()
+        .first()
+        .map(u8::is_ascii_uppercase)
+        .unwrap_or_default()
+}
+
+/// Check if expression is a function containing JSX, which can be a functional component.
+pub(crate) fn is_functional_component_expr(expr: &Expr) -> bool {
+    matches!(expr, Expr::Arrow(..) | Expr::Fn(..)) && contains_jsx(expr)
+}
+
+pub(crate) fn contains_jsx<N: VisitWith<JsxFinder>>(node: &N) -> bool {
+    let mut finder = JsxFinder { found: false };
+    node.visit_with(&mut finder);
+    finder.found
+}
+
+pub(crate) struct JsxFinder {
+    found: bool,
+}
+
+impl Visit for JsxFinder {
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+}
+
+/// Collect keys of object passed to `expose()` in setup function,
+/// where `expose` comes from setup context like `ctx.expose` or destructured `{ expose }`.
+/// It returns `None` if `expose()` isn't called or exposed object can't be analyzed statically.
+pub(crate) fn extract_exposed(setup_fn: &ExprOrSpread, context: &Pat) -> Option<Vec<String>> {
+    let callee = match context {
+        Pat::Ident(ident) => ExposeCallee::Member(ident.sym.clone(), ident.ctxt),
+        Pat::Object(ObjectPat { props, .. }) => props.iter().find_map(|prop| match prop {
+            ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => match (key, &**value) {
+                (PropName::Ident(IdentName { sym, .. }), Pat::Ident(local)) if sym == "expose" => {
+                    Some(ExposeCallee::Ident(local.sym.clone(), local.ctxt))
+                }
+                _ => None,
+            },
+            ObjectPatProp::Assign(AssignPatProp { key, .. }) if key.sym == "expose" => {
+                Some(ExposeCallee::Ident(key.sym.clone(), key.ctxt))
+            }
+            _ => None,
+        })?,
+        _ => return None,
+    };
+
+    let mut finder = ExposeFinder {
+        callee,
+        keys: Some(Default::default()),
+        found: false,
+    };
+    setup_fn.expr.visit_with(&mut finder);
+    if !finder.found {
+        return None;
+    }
+    finder
+        .keys
+        .map(|keys| keys.into_iter().map(|key| key.to_string()).collect())
+}
+
+enum ExposeCallee {
+    /// `ctx.expose()`
+    Member(Atom, SyntaxContext),
+    /// `expose()`
+    Ident(Atom, SyntaxContext),
+}
+
+struct ExposeFinder {
+    callee: ExposeCallee,
+    /// It will be `None` if exposed object can't be analyzed statically.
+    keys: Option<IndexSet<Atom>>,
+    found: bool,
+}
+
+impl Visit for ExposeFinder {
+    fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+        call_expr.visit_children_with(self);
+
+        let is_expose = match (&self.callee, call_expr.callee.as_expr().map(|expr| &**expr)) {
+            (
+                ExposeCallee::Member(sym, ctxt),
+                Some(Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                })),
+            ) => {
+                prop.sym == "expose"
+                    && obj
+                        .as_ident()
+                        .map(|ident| ident.sym == *sym && ident.ctxt == *ctxt)
+                        .unwrap_or_default()
+            }
+            (ExposeCallee::Ident(sym, ctxt), Some(Expr::Ident(ident))) => {
+                ident.sym == *sym && ident.ctxt == *ctxt
+            }
+            _ => false,
+        };
+        if !is_expose {
+            return;
+        }
+        self.found = true;
+
+        let Some(keys) = &mut self.keys else {
+            return;
+        };
+        let props = match call_expr.args.first() {
+            None => return,
+            Some(ExprOrSpread { expr, spread: None }) => match &**expr {
+                Expr::Object(ObjectLit { props, .. }) => props,
+                _ => {
+                    self.keys = None;
+                    return;
+                }
+            },
+            Some(..) => {
+                self.keys = None;
+                return;
+            }
+        };
+        let exposed = props
+            .iter()
+            .map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::Shorthand(ident) => Some(ident.sym.clone()),
+                    Prop::KeyValue(KeyValueProp { key, .. })
+                    | Prop::Getter(GetterProp { key, .. })
+                    | Prop::Setter(SetterProp { key, .. })
+                    | Prop::Method(MethodProp { key, .. }) => match key {
+                        PropName::Ident(ident) => Some(ident.sym.clone()),
+                        PropName::Str(str) => Some(str.value.clone()),
+                        _ => None,
+                    },
+                    Prop::Assign(..) => None,
+                },
+                PropOrSpread::Spread(..) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(exposed) = exposed {
+            keys.extend(exposed);
+        } else {
+            self.keys = None;
+        }
+    }
+}
@@ -47,17 +47,24 @@ fn test(input: PathBuf) {
             let unresolved_mark = Mark::new();
             (
                 resolver(unresolved_mark, Mark::new(), is_ts),
-                visit_mut_pass(VueJsxTransformVisitor::new(
-                    config.clone(),
-                    unresolved_mark,
-                    Some(tester.comments.clone()),
-                )),
+                visit_mut_pass(
+                    VueJsxTransformVisitor::new(
+                        config.clone(),
+                        unresolved_mark,
+                        Some(tester.comments.clone()),
+                    )
+                    .with_file_name(input.to_string_lossy())
+                    .with_cwd(env!("CARGO_MANIFEST_DIR"))
+                    .with_source_map(tester.cm.clone()),
+                ),
             )
         },
         &input,
         &output,
         FixtureTestConfig {
             module: Some(true),
+            // fixtures with `output.stderr` check reported errors
+            allow_error: output.with_extension("stderr").exists(),
             ..Default::default()
         },
     )
@@ -0,0 +1,5 @@
+const App = () => (
+  <div className="a" tabIndex={index} readOnly>
+    <span ariaLabel={label} />
+  </div>
+)
@@ -0,0 +1,15 @@
+import { createVNode as _createVNode } from "vue";
+const App = ()=>/*#__PURE__*/ _createVNode("div", {
+        "data-class": "a",
+        "tabindex": index,
+        "readOnly": true
+    }, [
+        /*#__PURE__*/ _createVNode("span", {
+            "aria-label": label
+        }, null, 8, [
+            "aria-label"
+        ])
+    ], 8, [
+        "tabindex",
+        "readOnly"
+    ]);
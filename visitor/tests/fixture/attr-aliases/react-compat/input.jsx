@@ -0,0 +1,12 @@
+const App = () => (
+  <form className={formClass}>
+    <label htmlFor="name" className="label">Name</label>
+    <input id="name" onChange={onNameChange} />
+    <input type="email" onChange={onEmailChange} />
+    <input type="checkbox" onChange={onToggle} />
+    <textarea onChange={onTextChange} />
+    <select onChange={onSelect} />
+    <div onDoubleClick={onDblclick} />
+    <Comp className="comp" onChange={onChange} onDoubleClick={onDoubleClick} />
+  </form>
+)
@@ -0,0 +1,52 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, normalizeClass as _normalizeClass, resolveComponent as _resolveComponent } from "vue";
+const App = ()=>/*#__PURE__*/ _createVNode("form", {
+        "class": /*#__PURE__*/ _normalizeClass(formClass)
+    }, [
+        /*#__PURE__*/ _createVNode("label", {
+            "for": "name",
+            "class": "label"
+        }, [
+            /*#__PURE__*/ _createTextVNode("Name")
+        ]),
+        /*#__PURE__*/ _createVNode("input", {
+            "id": "name",
+            "onInput": onNameChange
+        }, null, 40, [
+            "onInput"
+        ]),
+        /*#__PURE__*/ _createVNode("input", {
+            "type": "email",
+            "onInput": onEmailChange
+        }, null, 40, [
+            "onInput"
+        ]),
+        /*#__PURE__*/ _createVNode("input", {
+            "type": "checkbox",
+            "onChange": onToggle
+        }, null, 40, [
+            "onChange"
+        ]),
+        /*#__PURE__*/ _createVNode("textarea", {
+            "onInput": onTextChange
+        }, null, 40, [
+            "onInput"
+        ]),
+        /*#__PURE__*/ _createVNode("select", {
+            "onChange": onSelect
+        }, null, 40, [
+            "onChange"
+        ]),
+        /*#__PURE__*/ _createVNode("div", {
+            "onDblclick": onDblclick
+        }, null, 40, [
+            "onDblclick"
+        ]),
+        /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+            "class": "comp",
+            "onChange": onChange,
+            "onDoubleClick": onDoubleClick
+        }, null, 8, [
+            "onChange",
+            "onDoubleClick"
+        ])
+    ], 2);
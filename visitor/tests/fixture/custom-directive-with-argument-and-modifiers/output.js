@@ -1,28 +1,66 @@
-import {
-  Fragment as _Fragment,
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-  resolveDirective as _resolveDirective,
-  withDirectives as _withDirectives,
-} from "vue";
-_createVNode(_Fragment, null, [
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, 'y']]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, 'y', {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, void 0, {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, y, {
-    a: true,
-    b: true
-  }]]),
-  _withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("xxx"), x, y, {
-    a: true,
-    b: true
-  }]]),
+import { Fragment as _Fragment, createVNode as _createVNode, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, withDirectives as _withDirectives } from "vue";
+/*#__PURE__*/ _createVNode(_Fragment, null, [
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x,
+            'y'
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x,
+            'y',
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x,
+            void 0,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x,
+            y,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ]),
+    /*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+        [
+            _resolveDirective("xxx"),
+            x,
+            y,
+            {
+                a: true,
+                b: true
+            }
+        ]
+    ])
 ]);
@@ -1,7 +1,7 @@
-import {
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-  resolveDirective as _resolveDirective,
-  withDirectives as _withDirectives,
-} from "vue";
-_withDirectives(_createVNode(_resolveComponent("A"), null, null, 512), [[_resolveDirective("cus"), x]]);
+import { createVNode as _createVNode, resolveComponent as _resolveComponent, resolveDirective as _resolveDirective, withDirectives as _withDirectives } from "vue";
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, null, 512), [
+    [
+        _resolveDirective("cus"),
+        x
+    ]
+]);
@@ -1,2 +1,6 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-_createVNode("foo", null, [_createVNode("span", null, [_createTextVNode("foo")])]);
+/*#__PURE__*/ _createVNode("foo", null, [
+    /*#__PURE__*/ _createVNode("span", null, [
+        /*#__PURE__*/ _createTextVNode("foo")
+    ])
+]);
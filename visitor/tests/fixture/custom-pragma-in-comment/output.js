@@ -1,3 +1,6 @@
-/* @jsx custom */
-import { createTextVNode as _createTextVNode } from "vue";
-custom("div", { "id": "custom" }, [_createTextVNode("Hello")]);
+/* @jsx custom */ import { createTextVNode as _createTextVNode } from "vue";
+/*#__PURE__*/ custom("div", {
+    "id": "custom"
+}, [
+    /*#__PURE__*/ _createTextVNode("Hello")
+]);
@@ -1,2 +1,4 @@
 import { createTextVNode as _createTextVNode } from "vue";
-custom("div", null, [_createTextVNode("pragma")]);
+/*#__PURE__*/ custom("div", null, [
+    /*#__PURE__*/ _createTextVNode("pragma")
+]);
@@ -0,0 +1 @@
+const App = () => <div>hello</div>
@@ -0,0 +1,7 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+const App = ()=>/*#__PURE__*/ _createVNode("div", {
+        "data-v-7ba5bd90": "",
+        "data-source": "tests/fixture/dev-source-locations/custom-attr-name/input.jsx:1:19"
+    }, [
+        /*#__PURE__*/ _createTextVNode("hello")
+    ]);
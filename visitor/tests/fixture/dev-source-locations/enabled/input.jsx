@@ -0,0 +1,8 @@
+const App = () => (
+  <div class="app">
+    <Comp />
+    <span>{msg}</span>
+      <input
+        value={value} />
+  </div>
+)
@@ -0,0 +1,16 @@
+import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+const App = ()=>/*#__PURE__*/ _createVNode("div", {
+        "class": "app",
+        "data-v-inspector": "tests/fixture/dev-source-locations/enabled/input.jsx:2:3"
+    }, [
+        /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, null),
+        /*#__PURE__*/ _createVNode("span", {
+            "data-v-inspector": "tests/fixture/dev-source-locations/enabled/input.jsx:4:5"
+        }, [
+            msg
+        ]),
+        /*#__PURE__*/ _createVNode("input", {
+            "value": value,
+            "data-v-inspector": "tests/fixture/dev-source-locations/enabled/input.jsx:5:7"
+        }, null)
+    ]);
@@ -1,5 +1,7 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("Badge"), null, {
-  default: () => [slots.default()],
-  _: 1
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Badge"), null, {
+    default: ()=>[
+            slots.default()
+        ],
+    _: 1
 });
@@ -1,2 +1,4 @@
 import { createVNode as _createVNode } from "vue";
-_createVNode("h1", { "title": "" }, null);
+/*#__PURE__*/ _createVNode("h1", {
+    "title": ""
+}, null);
@@ -1,4 +1,8 @@
 import { Fragment as _Fragment, createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
 import { Fragment as _Fragment1 } from 'vue';
-const Root1 = () => _createVNode(_Fragment, null, [_createTextVNode("root1")]);
-const Root2 = () => _createVNode(_Fragment1, null, [_createTextVNode("root2")]);
+const Root1 = ()=>/*#__PURE__*/ _createVNode(_Fragment, null, [
+        /*#__PURE__*/ _createTextVNode("root1")
+    ]);
+const Root2 = ()=>/*#__PURE__*/ _createVNode(_Fragment1, null, [
+        /*#__PURE__*/ _createTextVNode("root2")
+    ]);
@@ -1,4 +1,4 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, {
-  default: () => "foo"
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, {
+    default: ()=>"foo"
 });
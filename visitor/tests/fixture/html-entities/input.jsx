@@ -0,0 +1,7 @@
+;<p title="&copy; 2024 &mdash; &check;">
+  a&nbsp;b &copy; &#x2014; &#169; &check; &bigstar; &NotANamedEntity; &amp;lt;
+</p>
+;<span>
+  text&nbsp;
+  &#x1F600; &#xZZ; &#65; &amp
+</span>
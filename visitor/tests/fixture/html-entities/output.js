@@ -0,0 +1,10 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+;
+/*#__PURE__*/ _createVNode("p", {
+    "title": "© 2024 — ✓"
+}, [
+    /*#__PURE__*/ _createTextVNode("a b © — © ✓ ★ &NotANamedEntity; &lt;")
+]);
+/*#__PURE__*/ _createVNode("span", null, [
+    /*#__PURE__*/ _createTextVNode("text  😀 &#xZZ; A &amp")
+]);
@@ -0,0 +1,12 @@
+import { defineComponent } from 'vue'
+
+const components = {}
+let Bar
+
+components.Foo = defineComponent(() => { })
+
+components['Baz'] = defineComponent({
+  setup() { },
+})
+
+Bar = defineComponent(() => { }, { inheritAttrs: false })
@@ -0,0 +1,14 @@
+import { defineComponent } from 'vue';
+const components = {};
+let Bar;
+components.Foo = defineComponent(()=>{}, {
+    name: "Foo"
+});
+components['Baz'] = defineComponent({
+    setup () {},
+    name: "Baz"
+});
+Bar = defineComponent(()=>{}, {
+    inheritAttrs: false,
+    name: "Bar"
+});
@@ -0,0 +1 @@
+export default (props: { msg: string }) => <div>{props.msg}</div>
@@ -0,0 +1,8 @@
+import { createVNode as _createVNode } from "vue";
+const Input = (props: {
+    msg: string;
+})=>/*#__PURE__*/ _createVNode("div", null, [
+        props.msg
+    ]);
+Input.displayName = "Input";
+export default Input;
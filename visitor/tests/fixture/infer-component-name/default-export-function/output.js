@@ -0,0 +1,5 @@
+import { createVNode as _createVNode } from "vue";
+export default function Input() {
+    return /*#__PURE__*/ _createVNode("div", null, null);
+}
+Input.displayName = "Input";
@@ -0,0 +1,4 @@
+export default function Foo() {
+  const Item = () => <li />
+  return <ul><Item /></ul>
+}
@@ -0,0 +1,8 @@
+import { createVNode as _createVNode } from "vue";
+export default function Foo() {
+    const Item = ()=>/*#__PURE__*/ _createVNode("li", null, null);
+    return /*#__PURE__*/ _createVNode("ul", null, [
+        /*#__PURE__*/ _createVNode(Item, null, null)
+    ]);
+}
+Foo.displayName = "Foo";
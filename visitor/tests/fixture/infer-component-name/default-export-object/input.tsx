@@ -0,0 +1,5 @@
+export default {
+  setup() {
+    return () => <div />
+  },
+}
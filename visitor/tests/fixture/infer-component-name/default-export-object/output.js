@@ -0,0 +1,7 @@
+import { createVNode as _createVNode } from "vue";
+export default {
+    setup () {
+        return ()=>/*#__PURE__*/ _createVNode("div", null, null);
+    },
+    name: "Input"
+};
@@ -0,0 +1,4 @@
+import { defineComponent } from 'vue';
+export default defineComponent(()=>{}, {
+    name: "Input"
+});
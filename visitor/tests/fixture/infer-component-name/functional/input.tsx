@@ -0,0 +1,17 @@
+import { defineComponent } from 'vue'
+
+export const Foo = (props: { msg: string }) => <div>{props.msg}</div>
+
+function Bar() {
+  return <span />
+}
+
+const Baz = defineComponent(() => () => <p />)
+
+const qux = () => <i />
+
+function scope() {
+  const Quux = function () {
+    return <b />
+  }
+}
@@ -0,0 +1,21 @@
+import { createVNode as _createVNode } from "vue";
+import { defineComponent } from 'vue';
+export const Foo = (props: {
+    msg: string;
+})=>/*#__PURE__*/ _createVNode("div", null, [
+        props.msg
+    ]);
+Foo.displayName = "Foo";
+function Bar() {
+    return /*#__PURE__*/ _createVNode("span", null, null);
+}
+Bar.displayName = "Bar";
+const Baz = defineComponent(()=>()=>/*#__PURE__*/ _createVNode("p", null, null), {
+    name: "Baz"
+});
+const qux = ()=>/*#__PURE__*/ _createVNode("i", null, null);
+function scope() {
+    const Quux = function() {
+        return /*#__PURE__*/ _createVNode("b", null, null);
+    };
+}
@@ -1,3 +1,5 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
 import { KeepAlive } from 'vue';
-_createVNode(KeepAlive, null, [_createTextVNode("123")]);
+/*#__PURE__*/ _createVNode(KeepAlive, null, [
+    /*#__PURE__*/ _createTextVNode("123")
+]);
@@ -1,3 +1,5 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
 import * as Vue from 'vue';
-_createVNode(Vue.KeepAlive, null, [_createTextVNode("123")]);
+/*#__PURE__*/ _createVNode(Vue.KeepAlive, null, [
+    /*#__PURE__*/ _createTextVNode("123")
+]);
@@ -1,3 +1,5 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
 import * as Vue from 'vue';
-_createVNode("div", null, [_createTextVNode("Vue")]);
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createTextVNode("Vue")
+]);
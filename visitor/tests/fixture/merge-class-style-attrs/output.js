@@ -1,5 +1,13 @@
-import { createVNode as _createVNode } from "vue";
-_createVNode("div", {
-  "class": ["a", b],
-  "style": ["color: red", s]
+import { createVNode as _createVNode, normalizeClass as _normalizeClass, normalizeStyle as _normalizeStyle } from "vue";
+/*#__PURE__*/ _createVNode("div", {
+    "class": /*#__PURE__*/ _normalizeClass([
+        "a",
+        b
+    ]),
+    "style": /*#__PURE__*/ _normalizeStyle([
+        {
+            color: "red"
+        },
+        s
+    ])
 }, null, 6);
@@ -1,6 +1,10 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode, mergeProps as _mergeProps } from "vue";
-_createVNode("button", _mergeProps({
-  "loading": true
+/*#__PURE__*/ _createVNode("button", /*#__PURE__*/ _mergeProps({
+    "loading": true
 }, x, {
-  "type": "submit"
-}), [_createTextVNode("btn")], 16, ["loading"]);
+    "type": "submit"
+}), [
+    /*#__PURE__*/ _createTextVNode("btn")
+], 16, [
+    "loading"
+]);
@@ -0,0 +1,31 @@
+const name = 'Vue'
+
+;<p>
+  Hello {`${name}`}, you have {n - read} messages
+  <b>!</b>
+</p>
+;<Comp>
+  Count: {count.value + 1}
+</Comp>
+;<div>
+  first line
+  {/* comment */}
+  second line
+  <span />
+</div>
+;<div>
+  <span />
+  {foo}{bar}
+</div>
+;<p>Hi {iconVNode}<b>!</b></p>
+;<p>
+  Total: {price * count} {props.unit}
+  <b>!</b>
+</p>
+;<p>
+  Hello {name}, you have {n} messages
+  <b>!</b>
+</p>
+;<Comp>
+  Count: {count.value}
+</Comp>
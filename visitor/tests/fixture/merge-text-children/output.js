@@ -0,0 +1,52 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent, toDisplayString as _toDisplayString } from "vue";
+const name = 'Vue';
+/*#__PURE__*/ _createVNode("p", null, [
+    /*#__PURE__*/ _createTextVNode("Hello " + /*#__PURE__*/ _toDisplayString(`${name}`) + ", you have " + /*#__PURE__*/ _toDisplayString(n - read) + " messages", 1),
+    /*#__PURE__*/ _createVNode("b", null, [
+        /*#__PURE__*/ _createTextVNode("!")
+    ])
+]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+    default: ()=>[
+            /*#__PURE__*/ _createTextVNode("Count: " + /*#__PURE__*/ _toDisplayString(count.value + 1), 1)
+        ],
+    _: 1
+});
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createTextVNode("first linesecond line"),
+    /*#__PURE__*/ _createVNode("span", null, null)
+]);
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createVNode("span", null, null),
+    foo,
+    bar
+]);
+/*#__PURE__*/ _createVNode("p", null, [
+    /*#__PURE__*/ _createTextVNode("Hi "),
+    iconVNode,
+    /*#__PURE__*/ _createVNode("b", null, [
+        /*#__PURE__*/ _createTextVNode("!")
+    ])
+]);
+/*#__PURE__*/ _createVNode("p", null, [
+    /*#__PURE__*/ _createTextVNode("Total: " + /*#__PURE__*/ _toDisplayString(price * count) + " ", 1),
+    props.unit,
+    /*#__PURE__*/ _createVNode("b", null, [
+        /*#__PURE__*/ _createTextVNode("!")
+    ])
+]);
+/*#__PURE__*/ _createVNode("p", null, [
+    /*#__PURE__*/ _createTextVNode("Hello " + /*#__PURE__*/ _toDisplayString(name) + ", you have ", 1),
+    n,
+    /*#__PURE__*/ _createTextVNode(" messages"),
+    /*#__PURE__*/ _createVNode("b", null, [
+        /*#__PURE__*/ _createTextVNode("!")
+    ])
+]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+    default: ()=>[
+            /*#__PURE__*/ _createTextVNode("Count: "),
+            count.value
+        ],
+    _: 1
+});
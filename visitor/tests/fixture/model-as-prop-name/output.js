@@ -1,5 +1,8 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("C"), {
-  "model": foo,
-  "onUpdate:model": $event => foo = $event
-}, null, 8, ["model", "onUpdate:model"]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("C"), {
+    "model": foo,
+    "onUpdate:model": ($event)=>foo = $event
+}, null, 8, [
+    "model",
+    "onUpdate:model"
+]);
@@ -1,5 +1,8 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, {
-  default: () => [foo, bar],
-  _: 1
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, {
+    default: ()=>[
+            foo,
+            bar
+        ],
+    _: 1
 });
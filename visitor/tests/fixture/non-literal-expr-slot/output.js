@@ -1,10 +1,12 @@
 import { createVNode as _createVNode, isVNode as _isVNode, resolveComponent as _resolveComponent } from "vue";
 function _isSlot(s) {
-  return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
+    return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
 }
 let _slot;
-const foo = () => 1;
-_createVNode(_resolveComponent("A"), null, _isSlot(_slot = foo()) ? _slot : {
-  default: () => [_slot],
-  _: 1
+const foo = ()=>1;
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, /*#__PURE__*/ _isSlot(_slot = foo()) ? _slot : {
+    default: ()=>[
+            _slot
+        ],
+    _: 1
 });
@@ -0,0 +1,13 @@
+;<div class={['a', { b: true, c: false }, ['d', null]]} />
+;<div class={{ active: 1, disabled: 0, 'is-open': 'yes' }} />
+;<div class={['a', 'b', isC && 'c', { d: true }, { e: isE }]} />
+;<div class={cls} />
+;<div style="color: red; margin: 0; background: url(a;b) /* comment */" />
+;<div style={['color: red', { fontSize: '12px' }, { color: 'blue' }]} />
+;<div style={['color: red', { margin: 0 }, dynamicStyle, 'padding: 1px']} />
+;<div style={{ color: c }} />
+;<Comp class={['a', { b: true }]} style="top: 0" />
+;<div class={['a', b]} {...attrs} />
+;<Comp style={compStyle} />
+;<Comp style={{ color: c }} />
+;<Comp style={[compStyle, 'color: red']} />
@@ -0,0 +1,85 @@
+import { createVNode as _createVNode, mergeProps as _mergeProps, normalizeClass as _normalizeClass, normalizeStyle as _normalizeStyle, resolveComponent as _resolveComponent } from "vue";
+;
+/*#__PURE__*/ _createVNode("div", {
+    "class": "a b d"
+}, null);
+/*#__PURE__*/ _createVNode("div", {
+    "class": "active is-open"
+}, null);
+/*#__PURE__*/ _createVNode("div", {
+    "class": /*#__PURE__*/ _normalizeClass([
+        "a b",
+        isC && 'c',
+        "d",
+        {
+            e: isE
+        }
+    ])
+}, null, 2);
+/*#__PURE__*/ _createVNode("div", {
+    "class": /*#__PURE__*/ _normalizeClass(cls)
+}, null, 2);
+/*#__PURE__*/ _createVNode("div", {
+    "style": {
+        color: "red",
+        margin: "0",
+        background: "url(a;b)"
+    }
+}, null);
+/*#__PURE__*/ _createVNode("div", {
+    "style": {
+        color: 'blue',
+        fontSize: '12px'
+    }
+}, null);
+/*#__PURE__*/ _createVNode("div", {
+    "style": /*#__PURE__*/ _normalizeStyle([
+        {
+            color: "red",
+            margin: 0
+        },
+        dynamicStyle,
+        {
+            padding: "1px"
+        }
+    ])
+}, null, 4);
+/*#__PURE__*/ _createVNode("div", {
+    "style": /*#__PURE__*/ _normalizeStyle({
+        color: c
+    })
+}, null, 4);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+    "class": "a b",
+    "style": {
+        top: "0"
+    }
+}, null);
+/*#__PURE__*/ _createVNode("div", /*#__PURE__*/ _mergeProps({
+    "class": [
+        'a',
+        b
+    ]
+}, attrs), null, 16);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+    "style": compStyle
+}, null, 8, [
+    "style"
+]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+    "style": {
+        color: c
+    }
+}, null, 8, [
+    "style"
+]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+    "style": /*#__PURE__*/ _normalizeStyle([
+        compStyle,
+        {
+            color: "red"
+        }
+    ])
+}, null, 8, [
+    "style"
+]);
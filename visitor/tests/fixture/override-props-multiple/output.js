@@ -1,5 +1,5 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), {
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), {
   "loading": true,
   ...a,
   b: 1,
@@ -1,2 +1,2 @@
 import { createVNode as _createVNode } from "vue";
-_createVNode("div", a, null);
+/*#__PURE__*/ _createVNode("div", a, null);
@@ -0,0 +1,9 @@
+;<input
+  placeholder="line one
+    line two"
+  title="  spaced  "
+/>
+;<p>
+  condensed
+  text
+</p>
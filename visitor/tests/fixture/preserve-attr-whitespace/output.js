@@ -0,0 +1,9 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+;
+/*#__PURE__*/ _createVNode("input", {
+    "placeholder": "line one\n    line two",
+    "title": "  spaced  "
+}, null);
+/*#__PURE__*/ _createVNode("p", null, [
+    /*#__PURE__*/ _createTextVNode("condensed text")
+]);
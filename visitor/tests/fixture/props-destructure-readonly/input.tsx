@@ -0,0 +1,10 @@
+import { defineComponent } from 'vue'
+
+defineComponent(({ count, step }: { count: number; step: number }) => {
+  const onClick = () => {
+    count++
+    --step
+    count = 0
+  }
+  return () => <button onClick={onClick}>{count}</button>
+})
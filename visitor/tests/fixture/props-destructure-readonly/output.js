@@ -0,0 +1,28 @@
+import { createVNode as _createVNode } from "vue";
+import { defineComponent } from 'vue';
+defineComponent((__props: {
+    count: number;
+    step: number;
+})=>{
+    const onClick = ()=>{
+        count++;
+        --step;
+        count = 0;
+    };
+    return ()=>/*#__PURE__*/ _createVNode("button", {
+            "onClick": onClick
+        }, [
+            __props.count
+        ]);
+}, {
+    props: {
+        count: {
+            type: Number,
+            required: true
+        },
+        step: {
+            type: Number,
+            required: true
+        }
+    }
+});
@@ -0,0 +1,20 @@
+import { defineComponent, watch } from 'vue'
+
+interface Props {
+  count: number
+  label?: string
+  'data-id'?: string
+  items?: string[]
+}
+
+defineComponent(({ count, label = 'hello', 'data-id': id, items = [], ...attrs }: Props) => {
+  watch(() => count, (count) => console.log(count))
+  const state = { count, id }
+  return () => <div id={id} {...attrs}>{label}: {count} {items.length} {state.count}</div>
+})
+
+defineComponent(function ({ count }: { count: number }) {
+  return () => <span>{count}</span>
+})
+
+defineComponent(({ count, ...rest }: Props) => () => <div {...rest}>{count}</div>)
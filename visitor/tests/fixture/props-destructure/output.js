@@ -0,0 +1,94 @@
+import { createPropsRestProxy as _createPropsRestProxy, createTextVNode as _createTextVNode, createVNode as _createVNode, mergeProps as _mergeProps } from "vue";
+import { defineComponent, watch } from 'vue';
+interface Props {
+    count: number;
+    label?: string;
+    'data-id'?: string;
+    items?: string[];
+}
+defineComponent((__props: Props)=>{
+    const attrs = _createPropsRestProxy(__props, [
+        "count",
+        "label",
+        "data-id",
+        "items"
+    ]);
+    watch(()=>__props.count, (count)=>console.log(count));
+    const state = {
+        count: __props.count,
+        id: __props["data-id"]
+    };
+    return ()=>/*#__PURE__*/ _createVNode("div", /*#__PURE__*/ _mergeProps({
+            "id": __props["data-id"]
+        }, attrs), [
+            __props.label,
+            /*#__PURE__*/ _createTextVNode(": "),
+            __props.count,
+            /*#__PURE__*/ _createTextVNode(" "),
+            __props.items.length,
+            /*#__PURE__*/ _createTextVNode(" "),
+            state.count
+        ]);
+}, {
+    props: {
+        count: {
+            type: Number,
+            required: true
+        },
+        label: {
+            type: String,
+            required: false,
+            default: 'hello'
+        },
+        'data-id': {
+            type: String,
+            required: false
+        },
+        items: {
+            type: Array,
+            required: false,
+            default: ()=>[]
+        }
+    }
+});
+defineComponent(function(__props: {
+    count: number;
+}) {
+    return ()=>/*#__PURE__*/ _createVNode("span", null, [
+            __props.count
+        ]);
+}, {
+    props: {
+        count: {
+            type: Number,
+            required: true
+        }
+    }
+});
+defineComponent((__props: Props)=>{
+    const rest = _createPropsRestProxy(__props, [
+        "count"
+    ]);
+    return ()=>/*#__PURE__*/ _createVNode("div", rest, [
+            __props.count
+        ]);
+}, {
+    props: {
+        count: {
+            type: Number,
+            required: true
+        },
+        label: {
+            type: String,
+            required: false
+        },
+        'data-id': {
+            type: String,
+            required: false
+        },
+        items: {
+            type: Array,
+            required: false
+        }
+    }
+});
@@ -0,0 +1,6 @@
+const App = () => (
+  <>
+    <div {...attrs} class="a" v-show={visible}>hello</div>
+    <Comp />
+  </>
+)
@@ -0,0 +1,14 @@
+import { Fragment as _Fragment, createTextVNode as _createTextVNode, createVNode as _createVNode, mergeProps as _mergeProps, resolveComponent as _resolveComponent, vShow as _vShow, withDirectives as _withDirectives } from "vue";
+const App = ()=>_createVNode(_Fragment, null, [
+        _withDirectives(_createVNode("div", _mergeProps(attrs, {
+            "class": "a"
+        }), [
+            _createTextVNode("hello")
+        ]), [
+            [
+                _vShow,
+                visible
+            ]
+        ]),
+        _createVNode(_resolveComponent("Comp"), null, null)
+    ]);
@@ -0,0 +1,2 @@
+const a = <p class={cls} style={style}>Count: {count + 1}</p>;
+const b = <Comp>{slots}</Comp>;
@@ -0,0 +1,14 @@
+import { createVNode as _createVNode, isVNode as _isVNode, normalizeClass as _normalizeClass, normalizeStyle as _normalizeStyle, resolveComponent as _resolveComponent, toDisplayString as _toDisplayString } from "vue";
+function _isSlot(s) {
+    return typeof s === "function" || ({}).toString.call(s) === "[object Object]" && !_isVNode(s);
+}
+const a = /*#__PURE__*/ _createVNode("p", {
+    "class": /*#__PURE__*/ _normalizeClass(cls),
+    "style": /*#__PURE__*/ _normalizeStyle(style)
+}, "Count: " + /*#__PURE__*/ _toDisplayString(count + 1), 7);
+const b = /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, /*#__PURE__*/ _isSlot(slots) ? slots : {
+    default: ()=>[
+            slots
+        ],
+    _: 1
+});
@@ -0,0 +1,8 @@
+const App = () => (
+  <div data-testid="app" class="app">
+    <input data-cy={id} value={value} />
+    <Comp data-test-id="comp" data-testid={id} {...{ 'data-testid': 'spread', foo }} />
+    <span {...attrs} data-testid="span" />
+    <p {...attrs} {...{ "data-cy": "p", title }} />
+  </div>
+)
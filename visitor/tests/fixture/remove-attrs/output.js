@@ -0,0 +1,17 @@
+import { createVNode as _createVNode, mergeProps as _mergeProps, resolveComponent as _resolveComponent } from "vue";
+const App = ()=>/*#__PURE__*/ _createVNode("div", {
+        "class": "app"
+    }, [
+        /*#__PURE__*/ _createVNode("input", {
+            "value": value
+        }, null, 8, [
+            "value"
+        ]),
+        /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), {
+            foo
+        }, null, 16),
+        /*#__PURE__*/ _createVNode("span", attrs, null, 16),
+        /*#__PURE__*/ _createVNode("p", /*#__PURE__*/ _mergeProps(attrs, {
+            title
+        }), null, 16)
+    ]);
@@ -0,0 +1,17 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+defineComponent((_, { emit }: { emit: (e: 'change', value: number) => void }) => {})
+
+interface Context {
+  emit(e: 'open' | 'close'): void
+}
+
+defineComponent((_, { emit }: Context) => {})
+
+type Emits = { submit: [] }
+
+defineComponent((_, { emit }: Pick<SetupContext<Emits>, 'emit'>) => {})
+
+type Ctx = SetupContext<{ reset: () => void }>
+
+defineComponent((_, ctx: Ctx & { attrs: {} }) => {})
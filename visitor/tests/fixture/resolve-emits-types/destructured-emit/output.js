@@ -0,0 +1,36 @@
+import { defineComponent, type SetupContext } from 'vue';
+defineComponent((_, { emit }: {
+    emit: (e: 'change', value: number) => void;
+})=>{}, {
+    emits: [
+        "change"
+    ]
+});
+interface Context {
+    emit(e: 'open' | 'close'): void;
+}
+defineComponent((_, { emit }: Context)=>{}, {
+    emits: [
+        "open",
+        "close"
+    ]
+});
+type Emits = {
+    submit: [];
+};
+defineComponent((_, { emit }: Pick<SetupContext<Emits>, 'emit'>)=>{}, {
+    emits: [
+        "submit"
+    ]
+});
+type Ctx = SetupContext<{
+    reset: () => void;
+}>;
+defineComponent((_, ctx: Ctx & {
+    attrs: {
+    };
+})=>{}, {
+    emits: [
+        "reset"
+    ]
+});
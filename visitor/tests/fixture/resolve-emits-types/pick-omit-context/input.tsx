@@ -0,0 +1,13 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+type Emits = { submit: []; change: [value: string] }
+
+defineComponent((_, ctx: Pick<SetupContext<Emits>, 'emit' | 'slots'>) => {})
+
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'attrs'>) => {})
+
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'emit'>) => {})
+
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'emit' | 'slots'>) => {})
+
+defineComponent((_, ctx: Pick<SetupContext<Emits>, 'attrs'>) => {})
@@ -0,0 +1,20 @@
+import { defineComponent, type SetupContext } from 'vue';
+type Emits = {
+    submit: [];
+    change: [value: string];
+};
+defineComponent((_, ctx: Pick<SetupContext<Emits>, 'emit' | 'slots'>)=>{}, {
+    emits: [
+        "submit",
+        "change"
+    ]
+});
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'attrs'>)=>{}, {
+    emits: [
+        "submit",
+        "change"
+    ]
+});
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'emit'>)=>{});
+defineComponent((_, ctx: Omit<SetupContext<Emits>, 'emit' | 'slots'>)=>{});
+defineComponent((_, ctx: Pick<SetupContext<Emits>, 'attrs'>)=>{});
@@ -0,0 +1,24 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+defineComponent((_, ctx: SetupContext<{ change: () => void }, { default: () => any }>) => {
+  const count = 0
+  ctx.expose({ count, reset() {}, 'focus': () => {} })
+  return () => <div />
+})
+
+defineComponent((_, { expose }: SetupContext) => {
+  expose({ open: () => {} })
+  expose({ close: () => {} })
+  return () => <div />
+})
+
+defineComponent(function (_, { expose: exposeApi }: SetupContext) {
+  exposeApi()
+  return () => <div />
+})
+
+defineComponent((_, ctx: SetupContext) => {
+  const api = {}
+  ctx.expose(api)
+  return () => <div />
+})
@@ -0,0 +1,38 @@
+import { createVNode as _createVNode } from "vue";
+import { defineComponent, type SetupContext } from 'vue';
+defineComponent((_, ctx: SetupContext<{
+    change: () => void;
+}, {
+    default: () => any;
+}>)=>{
+    const count = 0;
+    ctx.expose({
+        count,
+        reset () {},
+        'focus': ()=>{}
+    });
+    return ()=>/*#__PURE__*/ _createVNode("div", null, null);
+}, {
+    emits: [
+        "change"
+    ],
+    slots: Object
+});
+defineComponent((_, { expose }: SetupContext)=>{
+    expose({
+        open: ()=>{}
+    });
+    expose({
+        close: ()=>{}
+    });
+    return ()=>/*#__PURE__*/ _createVNode("div", null, null);
+});
+defineComponent(function(_, { expose: exposeApi }: SetupContext) {
+    exposeApi();
+    return ()=>/*#__PURE__*/ _createVNode("div", null, null);
+});
+defineComponent((_, ctx: SetupContext)=>{
+    const api = {};
+    ctx.expose(api);
+    return ()=>/*#__PURE__*/ _createVNode("div", null, null);
+});
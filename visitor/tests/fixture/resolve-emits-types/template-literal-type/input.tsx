@@ -0,0 +1,18 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+interface Model {
+  title: string
+  count: number
+}
+
+type Events = 'open' | 'close'
+
+defineComponent((_, ctx: SetupContext<(e: `update:${keyof Model}`) => void>) => {})
+
+defineComponent((_, ctx: SetupContext<(e: `on${Capitalize<Events>}` | Uppercase<'reset'>) => void>) => {})
+
+defineComponent((_, ctx: SetupContext<{
+  [K in `${Events}-${'start' | 'end'}`]: () => void
+}>) => {})
+
+defineComponent((props: Record<`is${Capitalize<Events>}`, boolean>) => {})
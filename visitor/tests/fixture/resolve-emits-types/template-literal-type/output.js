@@ -0,0 +1,41 @@
+import { defineComponent, type SetupContext } from 'vue';
+interface Model {
+    title: string;
+    count: number;
+}
+type Events = 'open' | 'close';
+defineComponent((_, ctx: SetupContext<(e: `update:${keyof Model}`) => void>)=>{}, {
+    emits: [
+        "update:title",
+        "update:count"
+    ]
+});
+defineComponent((_, ctx: SetupContext<(e: `on${Capitalize<Events>}` | Uppercase<'reset'>) => void>)=>{}, {
+    emits: [
+        "onOpen",
+        "onClose",
+        "RESET"
+    ]
+});
+defineComponent((_, ctx: SetupContext<{
+    [K in `${Events}-${'start' | 'end'}`]: () => void;
+}>)=>{}, {
+    emits: [
+        "open-start",
+        "open-end",
+        "close-start",
+        "close-end"
+    ]
+});
+defineComponent((props: Record<`is${Capitalize<Events>}`, boolean>)=>{}, {
+    props: {
+        isOpen: {
+            type: Boolean,
+            required: true
+        },
+        isClose: {
+            type: Boolean,
+            required: true
+        }
+    }
+});
@@ -0,0 +1,20 @@
+import { defineComponent, type SetupContext } from 'vue';
+defineComponent((_, ctx: SetupContext<{
+    (e: 'change', value: string) : void;
+    (e: 'change', value: number) : void;
+    (e: 'update', id: number, value: string) : void;
+    (e: 'update', value: string) : void;
+    (e: 'select' | 'change', value: string) : void;
+    (e: 'close') : void;
+    (e: 'close', reason: string) : void;
+    (e: 'open', payload: unknown) : void;
+    (e: 'open', payload: string) : void;
+}>)=>{}, {
+    emits: {
+        change: (value)=>typeof value === "string" || typeof value === "number",
+        update: (arg0, value)=>typeof arg0 === "number" && typeof value === "string" || typeof arg0 === "string" && value === undefined,
+        select: (value)=>typeof value === "string",
+        close: (reason)=>reason === undefined || typeof reason === "string",
+        open: null
+    }
+});
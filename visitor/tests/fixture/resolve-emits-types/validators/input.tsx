@@ -0,0 +1,14 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+defineComponent((_, ctx: SetupContext<{
+  change: (value: number) => void
+  select: [id: string | number, meta?: { index: number }]
+  'update:items': (items: string[], date: Date) => void
+  close: () => void
+  any: (value: unknown) => void
+}>) => {})
+
+defineComponent((_, ctx: SetupContext<{
+  (e: 'open' | 'toggle', force: boolean): void
+  (e: 'submit'): void
+}>) => {})
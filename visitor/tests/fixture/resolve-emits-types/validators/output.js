@@ -0,0 +1,28 @@
+import { defineComponent, type SetupContext } from 'vue';
+defineComponent((_, ctx: SetupContext<{
+    change: (value: number) => void;
+    select: [id: string | number, meta?: {
+            index: number;
+        }];
+    'update:items': (items: string[], date: Date) => void;
+    close: () => void;
+    any: (value: unknown) => void;
+}>)=>{}, {
+    emits: {
+        change: (value)=>typeof value === "number",
+        select: (id, meta)=>(typeof id === "string" || typeof id === "number") && (meta === undefined || meta !== null && typeof meta === "object"),
+        "update:items": (items, date)=>Array.isArray(items) && date instanceof Date,
+        close: null,
+        any: null
+    }
+});
+defineComponent((_, ctx: SetupContext<{
+    (e: 'open' | 'toggle', force: boolean) : void;
+    (e: 'submit') : void;
+}>)=>{}, {
+    emits: {
+        open: (force)=>typeof force === "boolean",
+        toggle: (force)=>typeof force === "boolean",
+        submit: null
+    }
+});
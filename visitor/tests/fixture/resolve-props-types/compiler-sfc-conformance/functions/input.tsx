@@ -0,0 +1,14 @@
+import { defineComponent } from 'vue'
+
+defineComponent(
+  (props: {
+    fn: (n: number) => void
+    functionRef: Function
+    method(): void
+    objectOrFn: {
+      (): void
+      foo: string
+    }
+    foo: ((item: any) => boolean) | null
+  }) => {}
+)
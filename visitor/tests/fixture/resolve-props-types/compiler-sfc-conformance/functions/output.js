@@ -0,0 +1,40 @@
+import { defineComponent } from 'vue';
+defineComponent((props: {
+    fn: (n: number) => void;
+    functionRef: Function;
+    method(): void;
+    objectOrFn: {
+        () : void;
+        foo: string;
+    };
+    foo: ((item: any) => boolean) | null;
+})=>{}, {
+    props: {
+        fn: {
+            type: Function,
+            required: true
+        },
+        functionRef: {
+            type: Function,
+            required: true
+        },
+        method: {
+            type: Function,
+            required: true
+        },
+        objectOrFn: {
+            type: [
+                Function,
+                Object
+            ],
+            required: true
+        },
+        foo: {
+            type: [
+                Function,
+                null
+            ],
+            required: true
+        }
+    }
+});
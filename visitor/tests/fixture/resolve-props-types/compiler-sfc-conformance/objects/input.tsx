@@ -0,0 +1,22 @@
+import { defineComponent } from 'vue'
+
+interface Test {}
+
+type Alias = number[]
+
+defineComponent(
+  (props: {
+    object: object
+    objectLiteral: { a: number }
+    objectRef: Object
+    dateTime: Date
+    array: string[]
+    arrayRef: Array<any>
+    tuple: [number, number]
+    set: Set<string>
+    recordRef: Record<string, null>
+    interface: Test
+    alias: Alias
+    error: Error
+  }) => {}
+)
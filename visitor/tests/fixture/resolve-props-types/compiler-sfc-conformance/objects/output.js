@@ -0,0 +1,71 @@
+import { defineComponent } from 'vue';
+interface Test {
+}
+type Alias = number[];
+defineComponent((props: {
+    object: object;
+    objectLiteral: {
+        a: number;
+    };
+    objectRef: Object;
+    dateTime: Date;
+    array: string[];
+    arrayRef: Array<any>;
+    tuple: [number, number];
+    set: Set<string>;
+    recordRef: Record<string, null>;
+    interface: Test;
+    alias: Alias;
+    error: Error;
+})=>{}, {
+    props: {
+        object: {
+            type: Object,
+            required: true
+        },
+        objectLiteral: {
+            type: Object,
+            required: true
+        },
+        objectRef: {
+            type: Object,
+            required: true
+        },
+        dateTime: {
+            type: Date,
+            required: true
+        },
+        array: {
+            type: Array,
+            required: true
+        },
+        arrayRef: {
+            type: Array,
+            required: true
+        },
+        tuple: {
+            type: Array,
+            required: true
+        },
+        set: {
+            type: Set,
+            required: true
+        },
+        recordRef: {
+            type: Object,
+            required: true
+        },
+        interface: {
+            type: Object,
+            required: true
+        },
+        alias: {
+            type: Array,
+            required: true
+        },
+        error: {
+            type: Error,
+            required: true
+        }
+    }
+});
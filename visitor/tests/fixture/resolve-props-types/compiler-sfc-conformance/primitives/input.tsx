@@ -0,0 +1,12 @@
+import { defineComponent } from 'vue'
+
+defineComponent(
+  (props: {
+    string: string
+    number: number
+    boolean: boolean
+    symbol: symbol
+    literal: 'foo'
+    optional?: any
+  }) => {}
+)
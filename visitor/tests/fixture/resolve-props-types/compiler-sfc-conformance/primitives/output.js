@@ -0,0 +1,36 @@
+import { defineComponent } from 'vue';
+defineComponent((props: {
+    string: string;
+    number: number;
+    boolean: boolean;
+    symbol: symbol;
+    literal: 'foo';
+    optional?: any;
+})=>{}, {
+    props: {
+        string: {
+            type: String,
+            required: true
+        },
+        number: {
+            type: Number,
+            required: true
+        },
+        boolean: {
+            type: Boolean,
+            required: true
+        },
+        symbol: {
+            type: Symbol,
+            required: true
+        },
+        literal: {
+            type: String,
+            required: true
+        },
+        optional: {
+            type: null,
+            required: false
+        }
+    }
+});
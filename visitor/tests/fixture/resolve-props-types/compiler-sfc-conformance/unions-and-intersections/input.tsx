@@ -0,0 +1,16 @@
+import { defineComponent } from 'vue'
+
+interface Test {}
+
+defineComponent(
+  (props: {
+    union: string | number
+    literalUnion: 'foo' | 'bar'
+    literalUnionNumber: 1 | 2 | 3 | 4 | 5
+    literalUnionMixed: 'foo' | 1 | boolean
+    intersection: Test & {}
+    intersection2: 'foo' & ('foo' | 'bar')
+    stringOrBoolean: string | boolean
+    booleanOrString: boolean | string
+  }) => {}
+)
@@ -0,0 +1,62 @@
+import { defineComponent } from 'vue';
+interface Test {
+}
+defineComponent((props: {
+    union: string | number;
+    literalUnion: 'foo' | 'bar';
+    literalUnionNumber: 1 | 2 | 3 | 4 | 5;
+    literalUnionMixed: 'foo' | 1 | boolean;
+    intersection: Test & {
+    };
+    intersection2: 'foo' & ('foo' | 'bar');
+    stringOrBoolean: string | boolean;
+    booleanOrString: boolean | string;
+})=>{}, {
+    props: {
+        union: {
+            type: [
+                String,
+                Number
+            ],
+            required: true
+        },
+        literalUnion: {
+            type: String,
+            required: true
+        },
+        literalUnionNumber: {
+            type: Number,
+            required: true
+        },
+        literalUnionMixed: {
+            type: [
+                String,
+                Number,
+                Boolean
+            ],
+            required: true
+        },
+        intersection: {
+            type: Object,
+            required: true
+        },
+        intersection2: {
+            type: String,
+            required: true
+        },
+        stringOrBoolean: {
+            type: [
+                String,
+                Boolean
+            ],
+            required: true
+        },
+        booleanOrString: {
+            type: [
+                Boolean,
+                String
+            ],
+            required: true
+        }
+    }
+});
@@ -0,0 +1,15 @@
+import { defineComponent } from 'vue'
+
+class Unknown {}
+
+defineComponent(
+  (props: {
+    unknown: UnknownType
+    unknownUnion: UnknownType | string
+    unknownIntersection: UnknownType & Object
+    unknownUnionWithBoolean: UnknownType | boolean
+    unknownUnionWithFunction: UnknownType | (() => any)
+    namedUnknown: Unknown
+    namedUnknownUnion: Unknown | boolean
+  }) => {}
+)
@@ -0,0 +1,48 @@
+import { defineComponent } from 'vue';
+class Unknown {
+}
+defineComponent((props: {
+    unknown: UnknownType;
+    unknownUnion: UnknownType | string;
+    unknownIntersection: UnknownType & Object;
+    unknownUnionWithBoolean: UnknownType | boolean;
+    unknownUnionWithFunction: UnknownType | (() => any);
+    namedUnknown: Unknown;
+    namedUnknownUnion: Unknown | boolean;
+})=>{}, {
+    props: {
+        unknown: {
+            type: null,
+            required: true
+        },
+        unknownUnion: {
+            type: null,
+            required: true
+        },
+        unknownIntersection: {
+            type: Object,
+            required: true
+        },
+        unknownUnionWithBoolean: {
+            type: Boolean,
+            required: true,
+            skipCheck: true
+        },
+        unknownUnionWithFunction: {
+            type: Function,
+            required: true,
+            skipCheck: true
+        },
+        namedUnknown: {
+            type: Object,
+            required: true
+        },
+        namedUnknownUnion: {
+            type: [
+                Object,
+                Boolean
+            ],
+            required: true
+        }
+    }
+});
@@ -0,0 +1,11 @@
+import { defineComponent } from 'vue'
+
+defineComponent(
+  (props: {
+    extract: Extract<1 | 2 | boolean, 2>
+    exclude: Exclude<1 | 2 | boolean, 2>
+    uppercase: Uppercase<'foo'>
+    params: Parameters<(foo: any) => void>
+    nonNull: NonNullable<string | null>
+  }) => {}
+)
@@ -0,0 +1,34 @@
+import { defineComponent } from 'vue';
+defineComponent((props: {
+    extract: Extract<1 | 2 | boolean, 2>;
+    exclude: Exclude<1 | 2 | boolean, 2>;
+    uppercase: Uppercase<'foo'>;
+    params: Parameters<(foo: any) => void>;
+    nonNull: NonNullable<string | null>;
+})=>{}, {
+    props: {
+        extract: {
+            type: Number,
+            required: true
+        },
+        exclude: {
+            type: [
+                Number,
+                Boolean
+            ],
+            required: true
+        },
+        uppercase: {
+            type: String,
+            required: true
+        },
+        params: {
+            type: Array,
+            required: true
+        },
+        nonNull: {
+            type: String,
+            required: true
+        }
+    }
+});
@@ -0,0 +1,3 @@
+import { defineComponent as dc } from 'vue'
+
+const Foo = dc((props: { foo: string }) => { })
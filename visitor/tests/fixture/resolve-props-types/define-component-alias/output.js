@@ -0,0 +1,12 @@
+import { defineComponent as dc } from 'vue';
+const Foo = dc((props: {
+    foo: string;
+})=>{}, {
+    props: {
+        foo: {
+            type: String,
+            required: true
+        }
+    },
+    name: "Foo"
+});
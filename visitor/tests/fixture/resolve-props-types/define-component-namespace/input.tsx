@@ -0,0 +1,5 @@
+import * as Vue from 'vue'
+
+const Foo = Vue.defineComponent((props: { foo: string }) => { })
+
+Vue.ref((props: { foo: string }) => { })
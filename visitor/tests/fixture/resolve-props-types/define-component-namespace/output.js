@@ -0,0 +1,15 @@
+import * as Vue from 'vue';
+const Foo = Vue.defineComponent((props: {
+    foo: string;
+})=>{}, {
+    props: {
+        foo: {
+            type: String,
+            required: true
+        }
+    },
+    name: "Foo"
+});
+Vue.ref((props: {
+    foo: string;
+})=>{});
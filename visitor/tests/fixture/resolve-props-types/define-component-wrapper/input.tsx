@@ -0,0 +1,6 @@
+import { defineTypedComponent } from '@/utils/component'
+import { defineComponent } from 'vue'
+
+const Foo = defineTypedComponent((props: { foo: string }) => { })
+
+const Bar = defineComponent((props: { bar: number }) => { })
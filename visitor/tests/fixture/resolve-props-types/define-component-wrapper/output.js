@@ -0,0 +1,24 @@
+import { defineTypedComponent } from '@/utils/component';
+import { defineComponent } from 'vue';
+const Foo = defineTypedComponent((props: {
+    foo: string;
+})=>{}, {
+    props: {
+        foo: {
+            type: String,
+            required: true
+        }
+    },
+    name: "Foo"
+});
+const Bar = defineComponent((props: {
+    bar: number;
+})=>{}, {
+    props: {
+        bar: {
+            type: Number,
+            required: true
+        }
+    },
+    name: "Bar"
+});
@@ -0,0 +1,12 @@
+import { defineComponent } from 'vue'
+
+interface Props {
+  size?: string
+  disabled?: boolean
+  items?: string[]
+  'data-id'?: number
+}
+
+defineComponent(({ size = 'md', disabled = false, items = [], 'data-id': id = 0 }: Props) => { })
+
+defineComponent(({ size = 'md', ...rest }: Props = { disabled: true }) => { })
@@ -0,0 +1,55 @@
+import { defineComponent } from 'vue';
+interface Props {
+    size?: string;
+    disabled?: boolean;
+    items?: string[];
+    'data-id'?: number;
+}
+defineComponent(({ size = 'md', disabled = false, items = [], 'data-id': id = 0 }: Props)=>{}, {
+    props: {
+        size: {
+            type: String,
+            required: false,
+            default: 'md'
+        },
+        disabled: {
+            type: Boolean,
+            required: false,
+            default: false
+        },
+        items: {
+            type: Array,
+            required: false,
+            default: ()=>[]
+        },
+        'data-id': {
+            type: Number,
+            required: false,
+            default: 0
+        }
+    }
+});
+defineComponent(({ size = 'md', ...rest }: Props = {
+    disabled: true
+})=>{}, {
+    props: {
+        size: {
+            type: String,
+            required: false,
+            default: 'md'
+        },
+        disabled: {
+            type: Boolean,
+            required: false,
+            default: true
+        },
+        items: {
+            type: Array,
+            required: false
+        },
+        'data-id': {
+            type: Number,
+            required: false
+        }
+    }
+});
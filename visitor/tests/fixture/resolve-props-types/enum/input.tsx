@@ -0,0 +1,27 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+enum Size {
+  Small = 'sm',
+  Large = 'lg',
+}
+
+const enum Level {
+  Low,
+  High,
+}
+
+enum Mixed {
+  A = 'a',
+  B = 1,
+}
+
+defineComponent((props: {
+  size: Size,
+  level: Level,
+  mixed: Mixed,
+  small: Size.Small,
+  high: Level.High,
+  sizeKey: keyof typeof Size,
+}) => { })
+
+defineComponent((_, ctx: SetupContext<{ (e: Size): void, (e: keyof typeof Level): void }>) => { })
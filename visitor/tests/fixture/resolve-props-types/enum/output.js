@@ -0,0 +1,62 @@
+import { defineComponent, type SetupContext } from 'vue';
+enum Size {
+    Small = 'sm',
+    Large = 'lg'
+}
+const enum Level {
+    Low,
+    High
+}
+enum Mixed {
+    A = 'a',
+    B = 1
+}
+defineComponent((props: {
+    size: Size;
+    level: Level;
+    mixed: Mixed;
+    small: Size.Small;
+    high: Level.High;
+    sizeKey: keyof typeof Size;
+})=>{}, {
+    props: {
+        size: {
+            type: String,
+            required: true
+        },
+        level: {
+            type: Number,
+            required: true
+        },
+        mixed: {
+            type: [
+                String,
+                Number
+            ],
+            required: true
+        },
+        small: {
+            type: String,
+            required: true
+        },
+        high: {
+            type: Number,
+            required: true
+        },
+        sizeKey: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent((_, ctx: SetupContext<{
+    (e: Size) : void;
+    (e: keyof typeof Level) : void;
+}>)=>{}, {
+    emits: [
+        "sm",
+        "lg",
+        "Low",
+        "High"
+    ]
+});
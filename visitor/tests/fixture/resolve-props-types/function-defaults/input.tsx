@@ -0,0 +1,21 @@
+import { defineComponent } from 'vue'
+
+interface Props {
+  format?: (value: number) => string
+  validate?: Function
+  onClick?: () => void
+  parse?: ((value: string) => number) | string
+  options?: { format: (value: number) => string }
+  factory?: () => string
+}
+
+defineComponent(({
+  format = (v) => String(v),
+  validate = function (value) { return !!value },
+  onClick = noop,
+  parse = Number.parseFloat,
+  options = { format: String },
+  factory = createFactory(),
+}: Props) => { })
+
+defineComponent((props: Props = { format: (v) => v.toFixed(2), onClick: noop, options: { format: String } }) => { })
@@ -0,0 +1,99 @@
+import { defineComponent } from 'vue';
+interface Props {
+    format?: (value: number) => string;
+    validate?: Function;
+    onClick?: () => void;
+    parse?: ((value: string) => number) | string;
+    options?: {
+        format: (value: number) => string;
+    };
+    factory?: () => string;
+}
+defineComponent(({ format = (v)=>String(v), validate = function(value) {
+    return !!value;
+}, onClick = noop, parse = Number.parseFloat, options = {
+    format: String
+}, factory = createFactory() }: Props)=>{}, {
+    props: {
+        format: {
+            type: Function,
+            required: false,
+            default: (v)=>String(v)
+        },
+        validate: {
+            type: Function,
+            required: false,
+            default: function(value) {
+                return !!value;
+            }
+        },
+        onClick: {
+            type: Function,
+            required: false,
+            default: noop
+        },
+        parse: {
+            type: [
+                Function,
+                String
+            ],
+            required: false,
+            default: Number.parseFloat,
+            skipFactory: true
+        },
+        options: {
+            type: Object,
+            required: false,
+            default: ()=>({
+                    format: String
+                })
+        },
+        factory: {
+            type: Function,
+            required: false,
+            default: createFactory()
+        }
+    }
+});
+defineComponent((props: Props = {
+    format: (v)=>v.toFixed(2),
+    onClick: noop,
+    options: {
+        format: String
+    }
+})=>{}, {
+    props: {
+        format: {
+            type: Function,
+            required: false,
+            default: (v)=>v.toFixed(2)
+        },
+        validate: {
+            type: Function,
+            required: false
+        },
+        onClick: {
+            type: Function,
+            required: false,
+            default: noop
+        },
+        parse: {
+            type: [
+                Function,
+                String
+            ],
+            required: false
+        },
+        options: {
+            type: Object,
+            required: false,
+            default: ()=>({
+                    format: String
+                })
+        },
+        factory: {
+            type: Function,
+            required: false
+        }
+    }
+});
@@ -0,0 +1,11 @@
+import { defineComponent } from 'vue'
+
+defineComponent(<T,>(props: { items: T[], selected: T }) => { })
+
+defineComponent(<T extends string | number, U extends { id: string }>(props: {
+  value: T,
+  item: U,
+  id: U['id'],
+}) => { })
+
+defineComponent(function <T extends { foo: string, bar?: number }>(props: T) { })
@@ -0,0 +1,56 @@
+import { defineComponent } from 'vue';
+defineComponent(<T>(props: {
+    items: T[];
+    selected: T;
+})=>{}, {
+    props: {
+        items: {
+            type: Array,
+            required: true
+        },
+        selected: {
+            type: null,
+            required: true
+        }
+    }
+});
+defineComponent(<T extends string | number, U extends {
+    id: string;
+}>(props: {
+    value: T;
+    item: U;
+    id: U['id'];
+})=>{}, {
+    props: {
+        value: {
+            type: [
+                String,
+                Number
+            ],
+            required: true
+        },
+        item: {
+            type: Object,
+            required: true
+        },
+        id: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent(function<T extends {
+    foo: string;
+    bar?: number;
+}>(props: T) {}, {
+    props: {
+        foo: {
+            type: String,
+            required: true
+        },
+        bar: {
+            type: Number,
+            required: false
+        }
+    }
+});
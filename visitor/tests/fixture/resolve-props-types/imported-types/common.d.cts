@@ -0,0 +1,3 @@
+export interface CommonProps {
+  count: number
+}
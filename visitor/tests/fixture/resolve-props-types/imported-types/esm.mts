@@ -0,0 +1,3 @@
+export interface EsmProps {
+  title: string
+}
@@ -0,0 +1,17 @@
+import { defineComponent } from 'vue'
+import type { Props } from './types'
+import type { BaseProps as Base } from '@/base'
+import type { Size, Extra } from './nested'
+import { Color, helper } from './types'
+import type { EsmProps } from './esm.mjs'
+import type { CommonProps } from './common.cjs'
+
+defineComponent((props: Props) => { })
+
+defineComponent((props: Base & { size: Size }) => { })
+
+defineComponent((props: Extra) => { })
+
+defineComponent((props: { color: Color }) => { helper() })
+
+defineComponent((props: EsmProps & CommonProps) => { })
@@ -0,0 +1,4 @@
+type ExtraProps = {
+  extra: Array<string>
+}
+export { ExtraProps as Extra }
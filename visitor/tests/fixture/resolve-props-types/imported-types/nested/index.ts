@@ -0,0 +1,2 @@
+export * from './size'
+export type { Extra } from './extra.js'
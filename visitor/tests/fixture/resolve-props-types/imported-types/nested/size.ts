@@ -0,0 +1 @@
+export type Size = 'small' | 'large'
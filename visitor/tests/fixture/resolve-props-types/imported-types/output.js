@@ -0,0 +1,73 @@
+import { defineComponent } from 'vue';
+import type { Props } from './types';
+import type { BaseProps as Base } from '@/base';
+import type { Size, Extra } from './nested';
+import { Color, helper } from './types';
+import type { EsmProps } from './esm.mjs';
+import type { CommonProps } from './common.cjs';
+defineComponent((props: Props)=>{}, {
+    props: {
+        foo: {
+            type: String,
+            required: true
+        },
+        bar: {
+            type: Number,
+            required: false
+        },
+        id: {
+            type: String,
+            required: true
+        },
+        internal: {
+            type: Boolean,
+            required: true
+        }
+    }
+});
+defineComponent((props: Base & {
+    size: Size;
+})=>{}, {
+    props: {
+        id: {
+            type: String,
+            required: true
+        },
+        size: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent((props: Extra)=>{}, {
+    props: {
+        extra: {
+            type: Array,
+            required: true
+        }
+    }
+});
+defineComponent((props: {
+    color: Color;
+})=>{
+    helper();
+}, {
+    props: {
+        color: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent((props: EsmProps & CommonProps)=>{}, {
+    props: {
+        title: {
+            type: String,
+            required: true
+        },
+        count: {
+            type: Number,
+            required: true
+        }
+    }
+});
@@ -0,0 +1,3 @@
+export interface BaseProps {
+  id: string
+}
@@ -0,0 +1,17 @@
+import type { BaseProps } from '@/base'
+
+interface Internal {
+  internal: boolean
+}
+
+export interface Props extends BaseProps, Internal {
+  foo: string
+  bar?: number
+}
+
+export enum Color {
+  Red = 'red',
+  Blue = 'blue',
+}
+
+export const helper = () => {}
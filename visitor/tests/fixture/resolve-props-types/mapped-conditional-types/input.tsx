@@ -0,0 +1,34 @@
+import { defineComponent } from 'vue'
+
+interface BaseProps {
+  id: string
+  title?: string
+  count: number
+}
+
+defineComponent((props: Readonly<Omit<BaseProps, 'id'>>) => { })
+
+defineComponent((props: Record<'a' | 'b', boolean>) => { })
+
+type Optional<T> = { [K in keyof T]?: T[K] }
+type Mutable<T> = { -readonly [K in keyof T]: T[K] }
+type Flags = { [K in 'x' | 'y']: boolean }
+
+defineComponent((props: Optional<BaseProps>) => { })
+defineComponent((props: Mutable<BaseProps>) => { })
+defineComponent((props: Flags) => { })
+
+type Keys = keyof BaseProps
+type NonId = Exclude<Keys, 'id'>
+type ValueOf<T> = T extends string ? 'text' : 'other'
+type IsText = string extends string ? { text: string } : { num: number }
+
+defineComponent((props: {
+  key: Keys,
+  field: Pick<BaseProps, NonId>['title'],
+  kind: ValueOf<string>,
+  value: number extends string ? string : number,
+  symbol: unique symbol,
+}) => { })
+
+defineComponent((props: IsText) => { })
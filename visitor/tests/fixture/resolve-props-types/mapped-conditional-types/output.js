@@ -0,0 +1,129 @@
+import { defineComponent } from 'vue';
+interface BaseProps {
+    id: string;
+    title?: string;
+    count: number;
+}
+defineComponent((props: Readonly<Omit<BaseProps, 'id'>>)=>{}, {
+    props: {
+        title: {
+            type: String,
+            required: false
+        },
+        count: {
+            type: Number,
+            required: true
+        }
+    }
+});
+defineComponent((props: Record<'a' | 'b', boolean>)=>{}, {
+    props: {
+        a: {
+            type: Boolean,
+            required: true
+        },
+        b: {
+            type: Boolean,
+            required: true
+        }
+    }
+});
+type Optional<T> = {
+    [K in keyof T]?: T[K];
+};
+type Mutable<T> = {
+    -readonly [K in keyof T]: T[K];
+};
+type Flags = {
+    [K in 'x' | 'y']: boolean;
+};
+defineComponent((props: Optional<BaseProps>)=>{}, {
+    props: {
+        id: {
+            type: String,
+            required: false
+        },
+        title: {
+            type: String,
+            required: false
+        },
+        count: {
+            type: Number,
+            required: false
+        }
+    }
+});
+defineComponent((props: Mutable<BaseProps>)=>{}, {
+    props: {
+        id: {
+            type: String,
+            required: true
+        },
+        title: {
+            type: String,
+            required: false
+        },
+        count: {
+            type: Number,
+            required: true
+        }
+    }
+});
+defineComponent((props: Flags)=>{}, {
+    props: {
+        x: {
+            type: Boolean,
+            required: true
+        },
+        y: {
+            type: Boolean,
+            required: true
+        }
+    }
+});
+type Keys = keyof BaseProps;
+type NonId = Exclude<Keys, 'id'>;
+type ValueOf<T> = T extends string ? 'text' : 'other';
+type IsText = string extends string ? {
+    text: string;
+} : {
+    num: number;
+};
+defineComponent((props: {
+    key: Keys;
+    field: Pick<BaseProps, NonId>['title'];
+    kind: ValueOf<string>;
+    value: number extends string ? string : number;
+    symbol: unique symbol;
+})=>{}, {
+    props: {
+        key: {
+            type: String,
+            required: true
+        },
+        field: {
+            type: String,
+            required: true
+        },
+        kind: {
+            type: String,
+            required: true
+        },
+        value: {
+            type: Number,
+            required: true
+        },
+        symbol: {
+            type: Symbol,
+            required: true
+        }
+    }
+});
+defineComponent((props: IsText)=>{}, {
+    props: {
+        text: {
+            type: String,
+            required: true
+        }
+    }
+});
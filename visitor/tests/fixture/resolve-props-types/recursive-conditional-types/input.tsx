@@ -0,0 +1,16 @@
+import { defineComponent } from 'vue'
+
+type Unwrap<T> = T extends Array<infer U> ? Unwrap<U> : T
+type Loop<T> = T extends string ? Loop<T> : never
+type Nested<T> = T extends string ? Nested<[T]> : T
+type Json<T> = T extends object ? { [K in keyof T]: Json<T[K]> } : T
+
+interface Props {
+  a: Unwrap<string[][]>
+  b: Loop<string>
+  c: Nested<string>
+  d: Json<{ a: number }>
+  e: number
+}
+
+defineComponent((props: Props) => { })
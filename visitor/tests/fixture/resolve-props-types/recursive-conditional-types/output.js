@@ -0,0 +1,40 @@
+import { defineComponent } from 'vue';
+type Unwrap<T> = T extends Array<infer U> ? Unwrap<U> : T;
+type Loop<T> = T extends string ? Loop<T> : never;
+type Nested<T> = T extends string ? Nested<[T]> : T;
+type Json<T> = T extends object ? {
+    [K in keyof T]: Json<T[K]>;
+} : T;
+interface Props {
+    a: Unwrap<string[][]>;
+    b: Loop<string>;
+    c: Nested<string>;
+    d: Json<{
+        a: number;
+    }>;
+    e: number;
+}
+defineComponent((props: Props)=>{}, {
+    props: {
+        a: {
+            type: null,
+            required: true
+        },
+        b: {
+            type: null,
+            required: true
+        },
+        c: {
+            type: null,
+            required: true
+        },
+        d: {
+            type: Object,
+            required: true
+        },
+        e: {
+            type: Number,
+            required: true
+        }
+    }
+});
@@ -300,7 +300,7 @@ defineComponent((props: {
 })=>{}, {
     props: {
         nonNullable: {
-            type: String,
+            type: null,
             required: true
         },
         exclude: {
@@ -0,0 +1,16 @@
+import { defineComponent } from 'vue'
+
+type Size = 'sm' | 'md' | 'lg'
+
+type DataAttrs = { [K in `data-${string}`]?: string }
+
+defineComponent(
+  (props: {
+    dataAttrs: DataAttrs
+    handlers: Record<`on${Capitalize<string>}`, () => void>
+    sizes: { [K in `${Size}-${number}`]: number }
+    size: `${Size}`
+  }) => {}
+)
+
+defineComponent((props: { label: string } & Record<`aria-${string}`, string>) => {})
@@ -0,0 +1,42 @@
+import { defineComponent } from 'vue';
+type Size = 'sm' | 'md' | 'lg';
+type DataAttrs = {
+    [K in `data-${string}`]?: string;
+};
+defineComponent((props: {
+    dataAttrs: DataAttrs;
+    handlers: Record<`on${Capitalize<string>}`, () => void>;
+    sizes: {
+        [K in `${Size}-${number}`]: number;
+    };
+    size: `${Size}`;
+})=>{}, {
+    props: {
+        dataAttrs: {
+            type: Object,
+            required: true
+        },
+        handlers: {
+            type: Object,
+            required: true
+        },
+        sizes: {
+            type: Object,
+            required: true
+        },
+        size: {
+            type: String,
+            required: true
+        }
+    }
+});
+defineComponent((props: {
+    label: string;
+} & Record<`aria-${string}`, string>)=>{}, {
+    props: {
+        label: {
+            type: String,
+            required: true
+        }
+    }
+});
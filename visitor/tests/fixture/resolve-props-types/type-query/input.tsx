@@ -0,0 +1,36 @@
+import { defineComponent } from 'vue'
+
+const defaults = {
+  size: 10,
+  label: 'text',
+  tags: ['a', 'b'],
+  onClick() {},
+}
+
+function useCounter() {
+  return { count: 0, increment: () => {} }
+}
+
+const useUser = async (id: string) => ({ id })
+
+class Store {
+  name = 'store'
+  items: string[] = []
+  get total() {
+    return 0
+  }
+  reset() {}
+}
+
+defineComponent((props: typeof defaults) => { })
+
+defineComponent((props: ReturnType<typeof useCounter>) => { })
+
+defineComponent((props: {
+  store: InstanceType<typeof Store>,
+  storeName: InstanceType<typeof Store>['name'],
+  user: ReturnType<typeof useUser>,
+  size: typeof defaults.size,
+  key: keyof typeof defaults,
+  counter: typeof useCounter,
+}) => { })
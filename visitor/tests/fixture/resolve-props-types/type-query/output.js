@@ -0,0 +1,94 @@
+import { defineComponent } from 'vue';
+const defaults = {
+    size: 10,
+    label: 'text',
+    tags: [
+        'a',
+        'b'
+    ],
+    onClick () {}
+};
+function useCounter() {
+    return {
+        count: 0,
+        increment: ()=>{}
+    };
+}
+const useUser = async (id: string)=>({
+        id
+    });
+class Store {
+    name = 'store';
+    items: string[] = [];
+    get total() {
+        return 0;
+    }
+    reset() {}
+}
+defineComponent((props: typeof defaults)=>{}, {
+    props: {
+        size: {
+            type: Number,
+            required: true
+        },
+        label: {
+            type: String,
+            required: true
+        },
+        tags: {
+            type: Array,
+            required: true
+        },
+        onClick: {
+            type: Function,
+            required: true
+        }
+    }
+});
+defineComponent((props: ReturnType<typeof useCounter>)=>{}, {
+    props: {
+        count: {
+            type: Number,
+            required: true
+        },
+        increment: {
+            type: Function,
+            required: true
+        }
+    }
+});
+defineComponent((props: {
+    store: InstanceType<typeof Store>;
+    storeName: InstanceType<typeof Store>['name'];
+    user: ReturnType<typeof useUser>;
+    size: typeof defaults.size;
+    key: keyof typeof defaults;
+    counter: typeof useCounter;
+})=>{}, {
+    props: {
+        store: {
+            type: Object,
+            required: true
+        },
+        storeName: {
+            type: String,
+            required: true
+        },
+        user: {
+            type: Promise,
+            required: true
+        },
+        size: {
+            type: Number,
+            required: true
+        },
+        key: {
+            type: String,
+            required: true
+        },
+        counter: {
+            type: Function,
+            required: true
+        }
+    }
+});
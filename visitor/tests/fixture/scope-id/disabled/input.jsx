@@ -0,0 +1,7 @@
+import { defineComponent } from 'vue'
+
+export default defineComponent({
+  setup() {
+    return () => <div>hello</div>
+  },
+})
@@ -0,0 +1,9 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+import { defineComponent } from 'vue';
+export default defineComponent({
+    setup () {
+        return ()=>/*#__PURE__*/ _createVNode("div", null, [
+                /*#__PURE__*/ _createTextVNode("hello")
+            ]);
+    }
+});
@@ -0,0 +1,15 @@
+import { defineComponent } from 'vue'
+
+const Comp = defineComponent({
+  setup() {
+    return () => (
+      <div class="wrapper">
+        <span>hello</span>
+        <Child />
+        <input />
+      </div>
+    )
+  },
+})
+
+const Functional = defineComponent(() => () => <p />)
@@ -0,0 +1,26 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+import { defineComponent } from 'vue';
+const Comp = defineComponent({
+    setup () {
+        return ()=>/*#__PURE__*/ _createVNode("div", {
+                "class": "wrapper",
+                "data-v-7ba5bd90": ""
+            }, [
+                /*#__PURE__*/ _createVNode("span", {
+                    "data-v-7ba5bd90": ""
+                }, [
+                    /*#__PURE__*/ _createTextVNode("hello")
+                ]),
+                /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Child"), null, null),
+                /*#__PURE__*/ _createVNode("input", {
+                    "data-v-7ba5bd90": ""
+                }, null)
+            ]);
+    },
+    __scopeId: "data-v-7ba5bd90"
+});
+const Functional = defineComponent(()=>()=>/*#__PURE__*/ _createVNode("p", {
+            "data-v-7ba5bd90": ""
+        }, null), {
+    __scopeId: "data-v-7ba5bd90"
+});
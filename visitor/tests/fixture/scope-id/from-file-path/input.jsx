@@ -0,0 +1,8 @@
+import { defineComponent } from 'vue'
+
+export default defineComponent({
+  name: 'Comp',
+  setup() {
+    return () => <div>hello</div>
+  },
+})
@@ -0,0 +1,13 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+import { defineComponent } from 'vue';
+export default defineComponent({
+    name: 'Comp',
+    setup () {
+        return ()=>/*#__PURE__*/ _createVNode("div", {
+                "data-v-220a1af4": ""
+            }, [
+                /*#__PURE__*/ _createTextVNode("hello")
+            ]);
+    },
+    __scopeId: "data-v-220a1af4"
+});
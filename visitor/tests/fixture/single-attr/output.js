@@ -1,2 +1,4 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-_createVNode("div", x, [_createTextVNode("single")], 16);
+/*#__PURE__*/ _createVNode("div", x, [
+    /*#__PURE__*/ _createTextVNode("single")
+], 16);
@@ -4,13 +4,13 @@ function _isSlot(s) {
 }
 const Component = (row)=>{
     let _slot, _slot2, _slot3;
-    return _createVNode(_resolveComponent("NSpace"), null, {
+    return /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("NSpace"), null, {
         default: ()=>[
-                _createVNode(_resolveComponent("NButton"), {
+                /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("NButton"), {
                     "type": "primary",
                     "secondary": true,
                     "onClick": handler1
-                }, _isSlot(_slot = t('text1')) ? _slot : {
+                }, /*#__PURE__*/ _isSlot(_slot = t('text1')) ? _slot : {
                     default: ()=>[
                             _slot
                         ],
@@ -19,9 +19,9 @@ const Component = (row)=>{
                     "secondary",
                     "onClick"
                 ]),
-                _createVNode(_resolveComponent("NButton"), {
+                /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("NButton"), {
                     "onClick": handler2
-                }, _isSlot(_slot2 = t('text2')) ? _slot2 : {
+                }, /*#__PURE__*/ _isSlot(_slot2 = t('text2')) ? _slot2 : {
                     default: ()=>[
                             _slot2
                         ],
@@ -29,10 +29,10 @@ const Component = (row)=>{
                 }, 8, [
                     "onClick"
                 ]),
-                _createVNode(_resolveComponent("NButton"), {
+                /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("NButton"), {
                     "type": "error",
                     "onClick": handler3
-                }, _isSlot(_slot3 = t('text3')) ? _slot3 : {
+                }, /*#__PURE__*/ _isSlot(_slot3 = t('text3')) ? _slot3 : {
                     default: ()=>[
                             _slot3
                         ],
@@ -1,4 +1,4 @@
 import { createVNode as _createVNode } from "vue";
 import { createVNode, Fragment as _Fragment } from 'vue';
 import { vShow } from 'vue';
-_createVNode(_Fragment, null, null);
+/*#__PURE__*/ _createVNode(_Fragment, null, null);
@@ -0,0 +1,25 @@
+const count = 0
+const label = `Item ${count}`
+let total: number = 0
+const price: string | number = getPrice()
+const icon = getIcon()
+let mutable = 0
+const flag: boolean = true
+
+const App = () => {
+  const doubled = count * 2
+  return (
+    <div>
+      <span>{count}</span>
+      <span>{label}</span>
+      <span>Total: {total}</span>
+      <span>{price}</span>
+      <span>{icon}</span>
+      <span>{mutable}</span>
+      <span>{flag}</span>
+      <Comp>
+        <span>{doubled}</span>
+      </Comp>
+    </div>
+  )
+}
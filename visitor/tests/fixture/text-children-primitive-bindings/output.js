@@ -0,0 +1,32 @@
+import { createVNode as _createVNode, resolveComponent as _resolveComponent, toDisplayString as _toDisplayString } from "vue";
+const count = 0;
+const label = `Item ${count}`;
+let total: number = 0;
+const price: string | number = getPrice();
+const icon = getIcon();
+let mutable = 0;
+const flag: boolean = true;
+const App = ()=>{
+    const doubled = count * 2;
+    return /*#__PURE__*/ _createVNode("div", null, [
+        /*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(count), 1),
+        /*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(label), 1),
+        /*#__PURE__*/ _createVNode("span", null, "Total: " + /*#__PURE__*/ _toDisplayString(total), 1),
+        /*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(price), 1),
+        /*#__PURE__*/ _createVNode("span", null, [
+            icon
+        ]),
+        /*#__PURE__*/ _createVNode("span", null, [
+            mutable
+        ]),
+        /*#__PURE__*/ _createVNode("span", null, [
+            flag
+        ]),
+        /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+            default: ()=>[
+                    /*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(doubled), 1)
+                ],
+            _: 2
+        })
+    ]);
+};
@@ -0,0 +1,20 @@
+const count = ref(0)
+
+;<span>{`${count.value}`}</span>
+;<span>Count: {count.value + 1}</span>
+;<span>{count.value * 2} items</span>
+;<span>Price: {price * quantity} USD</span>
+;<p class={cls}>{`${props.title}`}</p>
+;<div>{item.price * quantity}</div>
+;<div>{`${firstName} ${lastName}`}</div>
+;<span>
+  Total:
+  {-total}
+</span>
+;<span>{count.value}</span>
+;<button>Save {props.icon}</button>
+;<ul>{list}</ul>
+;<div>{renderItem()}</div>
+;<div>{visible && <span />}</div>
+;<div>static text</div>
+;<Comp>{count.value}</Comp>
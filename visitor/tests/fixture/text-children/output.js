@@ -0,0 +1,37 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, normalizeClass as _normalizeClass, resolveComponent as _resolveComponent, toDisplayString as _toDisplayString } from "vue";
+const count = ref(0);
+/*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(`${count.value}`), 1);
+/*#__PURE__*/ _createVNode("span", null, "Count: " + /*#__PURE__*/ _toDisplayString(count.value + 1), 1);
+/*#__PURE__*/ _createVNode("span", null, /*#__PURE__*/ _toDisplayString(count.value * 2) + " items", 1);
+/*#__PURE__*/ _createVNode("span", null, "Price: " + /*#__PURE__*/ _toDisplayString(price * quantity) + " USD", 1);
+/*#__PURE__*/ _createVNode("p", {
+    "class": /*#__PURE__*/ _normalizeClass(cls)
+}, /*#__PURE__*/ _toDisplayString(`${props.title}`), 3);
+/*#__PURE__*/ _createVNode("div", null, /*#__PURE__*/ _toDisplayString(item.price * quantity), 1);
+/*#__PURE__*/ _createVNode("div", null, /*#__PURE__*/ _toDisplayString(`${firstName} ${lastName}`), 1);
+/*#__PURE__*/ _createVNode("span", null, "Total:" + /*#__PURE__*/ _toDisplayString(-total), 1);
+/*#__PURE__*/ _createVNode("span", null, [
+    count.value
+]);
+/*#__PURE__*/ _createVNode("button", null, [
+    /*#__PURE__*/ _createTextVNode("Save "),
+    props.icon
+]);
+/*#__PURE__*/ _createVNode("ul", null, [
+    list
+]);
+/*#__PURE__*/ _createVNode("div", null, [
+    renderItem()
+]);
+/*#__PURE__*/ _createVNode("div", null, [
+    visible && /*#__PURE__*/ _createVNode("span", null, null)
+]);
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createTextVNode("static text")
+]);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+    default: ()=>[
+            count.value
+        ],
+    _: 1
+});
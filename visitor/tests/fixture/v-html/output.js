@@ -1,4 +1,6 @@
 import { createVNode as _createVNode } from "vue";
-_createVNode("h1", {
-  "innerHTML": "<div>foo</div>"
-}, null, 8, ["innerHTML"]);
+/*#__PURE__*/ _createVNode("h1", {
+    "innerHTML": "<div>foo</div>"
+}, null, 8, [
+    "innerHTML"
+]);
@@ -1,5 +1,5 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("Child"), {
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Child"), {
     "value": this.foo,
     "valueModifiers": {
         "double": true
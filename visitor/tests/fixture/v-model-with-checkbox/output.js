@@ -1,5 +1,12 @@
-import { createVNode as _createVNode,  vModelCheckbox as _vModelCheckbox, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("input", {
+import { createVNode as _createVNode, vModelCheckbox as _vModelCheckbox, withDirectives as _withDirectives } from "vue";
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode("input", {
     "type": "checkbox",
-    "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["onUpdate:modelValue"]), [[_vModelCheckbox, test]]);
+    "onUpdate:modelValue": ($event)=>test = $event
+}, null, 8, [
+    "onUpdate:modelValue"
+]), [
+    [
+        _vModelCheckbox,
+        test
+    ]
+]);
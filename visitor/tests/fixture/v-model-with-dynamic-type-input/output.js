@@ -1,5 +1,13 @@
 import { createVNode as _createVNode, vModelDynamic as _vModelDynamic, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("input", {
-  "type": type,
-  "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["type", "onUpdate:modelValue"]), [[_vModelDynamic, test]]);
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode("input", {
+    "type": type,
+    "onUpdate:modelValue": ($event)=>test = $event
+}, null, 8, [
+    "type",
+    "onUpdate:modelValue"
+]), [
+    [
+        _vModelDynamic,
+        test
+    ]
+]);
@@ -1,6 +1,15 @@
 import { createVNode as _createVNode, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
-_withDirectives(_createVNode("input", {
-  "onUpdate:modelValue": $event => test = $event
-}, null, 8, ["onUpdate:modelValue"]), [[_vModelText, test, void 0, {
-  lazy: true
-}]]);
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode("input", {
+    "onUpdate:modelValue": ($event)=>test = $event
+}, null, 8, [
+    "onUpdate:modelValue"
+]), [
+    [
+        _vModelText,
+        test,
+        void 0,
+        {
+            lazy: true
+        }
+    ]
+]);
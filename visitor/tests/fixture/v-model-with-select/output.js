@@ -1,15 +1,27 @@
-import {
-  createTextVNode as _createTextVNode,
-  createVNode as _createVNode,
-  vModelSelect as _vModelSelect,
-  withDirectives as _withDirectives,
-} from "vue";
-_withDirectives(_createVNode("select", {
-  "onUpdate:modelValue": $event => test = $event
-}, [_createVNode("option", {
-  "value": "1"
-}, [_createTextVNode("a")]), _createVNode("option", {
-  "value": 2
-}, [_createTextVNode("b")]), _createVNode("option", {
-  "value": 3
-}, [_createTextVNode("c")])], 8, ["onUpdate:modelValue"]), [[_vModelSelect, test]]);
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, vModelSelect as _vModelSelect, withDirectives as _withDirectives } from "vue";
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode("select", {
+    "onUpdate:modelValue": ($event)=>test = $event
+}, [
+    /*#__PURE__*/ _createVNode("option", {
+        "value": "1"
+    }, [
+        /*#__PURE__*/ _createTextVNode("a")
+    ]),
+    /*#__PURE__*/ _createVNode("option", {
+        "value": 2
+    }, [
+        /*#__PURE__*/ _createTextVNode("b")
+    ]),
+    /*#__PURE__*/ _createVNode("option", {
+        "value": 3
+    }, [
+        /*#__PURE__*/ _createTextVNode("c")
+    ])
+], 8, [
+    "onUpdate:modelValue"
+]), [
+    [
+        _vModelSelect,
+        test
+    ]
+]);
@@ -1,7 +1,9 @@
-import {
-  createTextVNode as _createTextVNode,
-  createVNode as _createVNode,
-  vShow as _vShow,
-  withDirectives as _withDirectives,
-} from "vue";
-_withDirectives(_createVNode("div", null, [_createTextVNode("vShow")], 512), [[_vShow, x]]);
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, vShow as _vShow, withDirectives as _withDirectives } from "vue";
+/*#__PURE__*/ _withDirectives(/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createTextVNode("vShow")
+], 512), [
+    [
+        _vShow,
+        x
+    ]
+]);
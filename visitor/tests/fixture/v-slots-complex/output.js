@@ -1,17 +1,17 @@
-import {
-  createTextVNode as _createTextVNode,
-  createVNode as _createVNode,
-  resolveComponent as _resolveComponent,
-} from "vue";
-_createVNode("div", null, [
-  _createVNode(_resolveComponent("Comp"), null, {
-    default: () => [_createTextVNode("content")],
-    ...slots,
-    _: 1,
-  }),
-  _createVNode(_resolveComponent("Comp"), null, {
-    default: () => [_createTextVNode("content")],
-    a: b,
-    _: 1,
-  }),
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+        default: ()=>[
+                /*#__PURE__*/ _createTextVNode("content")
+            ],
+        ...slots,
+        _: 1
+    }),
+    /*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("Comp"), null, {
+        default: ()=>[
+                /*#__PURE__*/ _createTextVNode("content")
+            ],
+        a: b,
+        _: 1
+    })
 ]);
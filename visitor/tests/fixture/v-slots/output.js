@@ -1,2 +1,2 @@
 import { createVNode as _createVNode, resolveComponent as _resolveComponent } from "vue";
-_createVNode(_resolveComponent("A"), null, slots);
+/*#__PURE__*/ _createVNode(/*#__PURE__*/ _resolveComponent("A"), null, slots);
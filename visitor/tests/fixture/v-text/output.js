@@ -1,4 +1,6 @@
 import { createVNode as _createVNode } from "vue";
-_createVNode("div", {
-  "textContent": text
-}, null, 8, ["textContent"]);
+/*#__PURE__*/ _createVNode("div", {
+    "textContent": text
+}, null, 8, [
+    "textContent"
+]);
@@ -0,0 +1,15 @@
+;<div>
+  <pre>
+    const a = 1
+      const b = 2
+    <b>  bold  </b>
+  </pre>
+  <textarea>
+    first line
+    second line
+  </textarea>
+  <p>
+    condensed
+    text
+  </p>
+</div>
@@ -0,0 +1,17 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+;
+/*#__PURE__*/ _createVNode("div", null, [
+    /*#__PURE__*/ _createVNode("pre", null, [
+        /*#__PURE__*/ _createTextVNode("    const a = 1\n      const b = 2\n    "),
+        /*#__PURE__*/ _createVNode("b", null, [
+            /*#__PURE__*/ _createTextVNode("  bold  ")
+        ]),
+        /*#__PURE__*/ _createTextVNode("\n  ")
+    ]),
+    /*#__PURE__*/ _createVNode("textarea", null, [
+        /*#__PURE__*/ _createTextVNode("    first line\n    second line\n  ")
+    ]),
+    /*#__PURE__*/ _createVNode("p", null, [
+        /*#__PURE__*/ _createTextVNode("condensed text")
+    ])
+]);
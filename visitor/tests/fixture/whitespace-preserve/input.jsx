@@ -0,0 +1,5 @@
+;<div title="a
+  b">
+  Hello   world
+  <span>  padded  </span>
+</div>
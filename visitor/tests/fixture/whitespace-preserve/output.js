@@ -0,0 +1,11 @@
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+;
+/*#__PURE__*/ _createVNode("div", {
+    "title": "a\n  b"
+}, [
+    /*#__PURE__*/ _createTextVNode("\n  Hello   world\n  "),
+    /*#__PURE__*/ _createVNode("span", null, [
+        /*#__PURE__*/ _createTextVNode("  padded  ")
+    ]),
+    /*#__PURE__*/ _createTextVNode("\n")
+]);
@@ -1,2 +1,4 @@
 import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
-_createVNode("a", null, [_createTextVNode("a")]);
+/*#__PURE__*/ _createVNode("a", null, [
+    /*#__PURE__*/ _createTextVNode("a")
+]);
@@ -0,0 +1,53 @@
+use std::{fs, path::PathBuf};
+use swc_core::{
+    common::{comments::SingleThreadedComments, FileName, Mark},
+    ecma::{
+        ast::EsVersion,
+        parser::{parse_file_as_module, Syntax, TsSyntax},
+        transforms::base::resolver,
+        visit::VisitMutWith,
+    },
+    plugin::errors::HANDLER,
+};
+use swc_vue_jsx_visitor::{Options, VueJsxTransformVisitor};
+
+#[testing::fixture("tests/metadata/**/input.tsx")]
+fn test(input: PathBuf) {
+    let output = input.with_file_name("metadata.json");
+    let code = fs::read_to_string(&input).unwrap();
+
+    let json = testing::run_test2(false, |cm, handler| {
+        let source_file = cm.new_source_file(FileName::Real(input.clone()).into(), code);
+        let mut module = parse_file_as_module(
+            &source_file,
+            Syntax::Typescript(TsSyntax {
+                tsx: true,
+                ..Default::default()
+            }),
+            EsVersion::latest(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        let unresolved_mark = Mark::new();
+        module.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), true));
+        let mut visitor = VueJsxTransformVisitor::<SingleThreadedComments>::new(
+            Options {
+                resolve_type: true,
+                ..Default::default()
+            },
+            unresolved_mark,
+            None,
+        )
+        .with_file_name(input.to_string_lossy());
+        HANDLER.set(&handler, || module.visit_mut_with(&mut visitor));
+
+        Ok(serde_json::to_string_pretty(visitor.component_metadata()).unwrap())
+    })
+    .unwrap();
+
+    testing::NormalizedOutput::from(json)
+        .compare_to_file(output)
+        .unwrap();
+}
@@ -0,0 +1,38 @@
+import { defineComponent, type SetupContext } from 'vue'
+
+interface Props {
+  title: string
+  size?: 'sm' | 'md' | 'lg'
+  count: number | string
+  onSelect?: (value: number) => void
+}
+
+type Emits = {
+  change: [value: number]
+  'update:title': [title: string]
+}
+
+interface Slots {
+  default: () => any
+  footer: (props: { count: number }) => any
+}
+
+export const Card = defineComponent(
+  ({ size = 'md' }: Props, ctx: SetupContext<Emits, Slots>) => {
+    ctx.expose({ focus() {}, size })
+    return () => <div />
+  },
+)
+
+export default defineComponent(
+  (props: { visible: boolean; label?: string } = { label: 'OK' }) => {
+    return () => <div />
+  },
+  { name: 'ConfirmDialog' },
+)
+
+export const Unnamed = defineComponent({
+  setup() {
+    return () => <div />
+  },
+})